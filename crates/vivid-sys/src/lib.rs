@@ -39,6 +39,21 @@ pub struct VividOperator {
     _private: [u8; 0],
 }
 
+// =============================================================================
+// Callback Types
+// =============================================================================
+
+/// Called whenever the core finishes a compile (including internal auto-reloads)
+/// The status pointer is only valid for the duration of the call
+pub type VividCompileCallback =
+    Option<unsafe extern "C" fn(status: *const VividCompileStatus, user_data: *mut c_void)>;
+
+/// Called when an operator fails at runtime and is isolated (its output goes black)
+/// Both strings are only valid for the duration of the call
+pub type VividOperatorErrorCallback = Option<
+    unsafe extern "C" fn(op_name: *const c_char, message: *const c_char, user_data: *mut c_void),
+>;
+
 // =============================================================================
 // Result Codes
 // =============================================================================
@@ -100,6 +115,104 @@ pub enum VividParamType {
     DeviceList = 11,
 }
 
+// =============================================================================
+// Parameter Binding Enum
+// =============================================================================
+
+/// What drives a parameter's value
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VividParamBinding {
+    Static = 0,
+    Expression = 1,
+    Midi = 2,
+    Automated = 3,
+    Osc = 4,
+}
+
+// =============================================================================
+// Input Default Enum
+// =============================================================================
+
+/// What an operator reads from an input slot that has nothing connected
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VividInputDefault {
+    None = 0,
+    Black = 1,
+    White = 2,
+    PassThrough = 3,
+    LastFrame = 4,
+}
+
+// =============================================================================
+// Context Creation Stage Enum
+// =============================================================================
+
+/// GPU setup step reached when creating a windowed context
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VividCreateStage {
+    Complete = 0,
+    Instance = 1,
+    Surface = 2,
+    Adapter = 3,
+    Device = 4,
+}
+
+// =============================================================================
+// Load State Enum
+// =============================================================================
+
+/// How completely the current project loaded
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VividLoadState {
+    Empty = 0,
+    PartiallyLoaded = 1,
+    FullyLoaded = 2,
+}
+
+// =============================================================================
+// Color Space Enum
+// =============================================================================
+
+/// Color space of the chain output texture
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VividColorSpace {
+    Unknown = 0,
+    Srgb = 1,
+    LinearSrgb = 2,
+    DisplayP3 = 3,
+}
+
+// =============================================================================
+// Alpha Mode Enum
+// =============================================================================
+
+/// How the chain output encodes its alpha channel
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VividAlphaMode {
+    Opaque = 0,
+    Premultiplied = 1,
+    Straight = 2,
+}
+
+// =============================================================================
+// Color Format Enum
+// =============================================================================
+
+/// Texture format of the chain output
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VividColorFormat {
+    Rgba8Unorm = 0,
+    Rgba16Float = 1,
+    Bgra8Unorm = 2,
+}
+
 // =============================================================================
 // Configuration Structures
 // =============================================================================
@@ -111,6 +224,9 @@ pub struct VividContextConfig {
     pub width: c_int,
     pub height: c_int,
     pub enable_validation: bool,
+    /// MSAA sample count (1 disables multisampling)
+    pub sample_count: c_int,
+    pub format: VividColorFormat,
 }
 
 impl Default for VividContextConfig {
@@ -119,6 +235,8 @@ impl Default for VividContextConfig {
             width: 1280,
             height: 720,
             enable_validation: false,
+            sample_count: 1,
+            format: VividColorFormat::Rgba8Unorm,
         }
     }
 }
@@ -143,6 +261,104 @@ pub struct VividTextureInfo {
     pub has_alpha: bool,
 }
 
+/// Source location of an operator declaration
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VividSourceLocation {
+    pub file: *const c_char,
+    pub line: c_int,
+    pub column: c_int,
+}
+
+/// Operator name paired with its current scalar output
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VividNamedValue {
+    pub name: *const c_char,
+    pub value: c_float,
+}
+
+/// A button or key state change in an input frame
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VividInputEvent {
+    pub code: c_int,
+    pub pressed: bool,
+}
+
+/// Input accumulated since the last frame, applied in one call
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VividInputFrame {
+    pub has_mouse_position: bool,
+    pub mouse_x: c_float,
+    pub mouse_y: c_float,
+    pub scroll_dx: c_float,
+    pub scroll_dy: c_float,
+    pub mouse_buttons: *const VividInputEvent,
+    pub mouse_button_count: c_int,
+    pub keys: *const VividInputEvent,
+    pub key_count: c_int,
+}
+
+/// Diagnostics filled in by vivid_context_create_with_window_ex
+/// String fields are NULL when unknown and valid until the next create call
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VividContextDiagnostics {
+    pub stage: VividCreateStage,
+    pub backend: *const c_char,
+    pub adapter_name: *const c_char,
+    pub driver: *const c_char,
+    pub message: *const c_char,
+}
+
+/// Options for exporting a standalone app
+/// project_path is required; other NULL fields use defaults (project dir,
+/// project name, host platform)
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VividExportOptions {
+    pub project_path: *const c_char,
+    pub output_dir: *const c_char,
+    pub app_name: *const c_char,
+    pub platform: *const c_char,
+}
+
+/// A platform the exporter knows how to bundle for
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VividBundleTarget {
+    pub id: *const c_char,
+    pub display_name: *const c_char,
+    pub available: bool,
+    /// Why the target can't be built on this host (NULL if available)
+    pub unavailable_reason: *const c_char,
+}
+
+/// An example project shipped under the vivid root's `projects/` directory
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VividExampleProject {
+    /// Path relative to `projects/` (e.g. "getting-started/02-operator-pipeline")
+    pub id: *const c_char,
+    pub name: *const c_char,
+    /// Description from the project's manifest (NULL if it has none)
+    pub description: *const c_char,
+    /// Absolute path to the project directory
+    pub path: *const c_char,
+}
+
+/// Counters accumulated since context creation (unaffected by time resets)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VividSessionStats {
+    pub total_frames: u64,
+    /// Wall-clock seconds spent inside process/render calls
+    pub total_render_seconds: c_double,
+    pub uptime_seconds: c_double,
+}
+
 /// Parameter declaration for introspection
 #[repr(C)]
 #[derive(Debug)]
@@ -155,6 +371,11 @@ pub struct VividParamDecl {
     pub string_default: *const c_char,
     pub enum_count: c_int,
     pub enum_labels: *const *const c_char,
+    pub read_only: bool,
+    /// Display unit (e.g. "Hz", "px", "deg"), or NULL if unitless
+    pub unit: *const c_char,
+    /// Tooltip text, or NULL to fall back to vivid_operator_get_param_help()
+    pub description: *const c_char,
 }
 
 // =============================================================================
@@ -194,6 +415,16 @@ extern "C" {
         out_ctx: *mut *mut VividContext,
     ) -> VividResult;
 
+    /// Same as vivid_context_create_with_window, but also reports which GPU
+    /// setup stage failed and the adapter/backend that was tried.
+    /// out_diagnostics is filled on both success and failure.
+    pub fn vivid_context_create_with_window_ex(
+        native_window: *mut c_void,
+        config: *const VividContextConfig,
+        out_ctx: *mut *mut VividContext,
+        out_diagnostics: *mut VividContextDiagnostics,
+    ) -> VividResult;
+
     /// Render a complete frame (chain output + visualizer UI)
     /// Only valid for contexts created with vivid_context_create_with_window
     pub fn vivid_context_render_frame(ctx: *mut VividContext) -> VividResult;
@@ -212,6 +443,12 @@ extern "C" {
     /// Check if visualizer UI is visible
     pub fn vivid_context_is_visualizer_visible(ctx: *mut VividContext) -> bool;
 
+    /// Draw a neutral "no project loaded" screen instead of black while no project is loaded
+    pub fn vivid_context_set_placeholder(ctx: *mut VividContext, enabled: bool);
+
+    /// Check if the placeholder screen is enabled
+    pub fn vivid_context_is_placeholder_enabled(ctx: *mut VividContext) -> bool;
+
     /// Get the name of the currently selected operator in the visualizer
     /// Returns NULL if no operator is selected
     pub fn vivid_context_get_selected_operator(ctx: *mut VividContext) -> *const c_char;
@@ -219,6 +456,56 @@ extern "C" {
     /// Select an operator in the visualizer by name
     pub fn vivid_context_select_operator(ctx: *mut VividContext, name: *const c_char);
 
+    /// Replace the visualizer selection with the given operator names
+    /// Unknown names are ignored; a count of 0 clears the selection
+    pub fn vivid_context_set_selection(
+        ctx: *mut VividContext,
+        names: *const *const c_char,
+        count: c_int,
+    );
+
+    /// Get the number of selected operators in the visualizer
+    pub fn vivid_context_get_selection_count(ctx: *mut VividContext) -> c_int;
+
+    /// Get a selected operator name by index (in selection order)
+    pub fn vivid_context_get_selection(ctx: *mut VividContext, index: c_int) -> *const c_char;
+
+    /// Set the visualizer's graph camera (graph-space center and zoom factor)
+    pub fn vivid_context_set_graph_view(
+        ctx: *mut VividContext,
+        center_x: f32,
+        center_y: f32,
+        zoom: f32,
+    );
+
+    /// Center the visualizer's graph camera on an operator's node, keeping the zoom
+    /// Returns false if no operator has this name
+    pub fn vivid_context_focus_operator(ctx: *mut VividContext, name: *const c_char) -> bool;
+
+    /// Enable safe mode: projects loaded afterwards start with every operator bypassed
+    pub fn vivid_context_set_safe_mode(ctx: *mut VividContext, enabled: bool);
+
+    /// Check if safe mode is enabled
+    pub fn vivid_context_is_safe_mode(ctx: *mut VividContext) -> bool;
+
+    /// Set the callback invoked after every compile, or NULL to clear it
+    pub fn vivid_context_set_compile_callback(
+        ctx: *mut VividContext,
+        callback: VividCompileCallback,
+        user_data: *mut c_void,
+    );
+
+    /// Set the callback invoked when an operator fails at runtime, or NULL to clear it
+    pub fn vivid_context_set_operator_error_callback(
+        ctx: *mut VividContext,
+        callback: VividOperatorErrorCallback,
+        user_data: *mut c_void,
+    );
+
+    /// Check if the GPU device has been lost (driver reset, GPU switch, sleep/wake)
+    /// A context with a lost device must be destroyed and recreated
+    pub fn vivid_context_is_device_lost(ctx: *mut VividContext) -> bool;
+
     /// Destroy a context and free all resources
     pub fn vivid_context_destroy(ctx: *mut VividContext);
 
@@ -235,9 +522,21 @@ extern "C" {
     /// Configure asset search paths before creating a context (for embedded use)
     pub fn vivid_configure_asset_paths(vivid_root: *const c_char) -> VividResult;
 
+    /// Enable or disable watching FilePath-referenced assets for changes
+    /// Changed files are re-imported by their operators (no recompile)
+    pub fn vivid_context_set_asset_watch(ctx: *mut VividContext, enabled: bool);
+
+    /// Check if asset watching is enabled
+    pub fn vivid_context_is_asset_watch_enabled(ctx: *mut VividContext) -> bool;
+
     /// Reload the current project
     pub fn vivid_context_reload(ctx: *mut VividContext) -> VividResult;
 
+    /// Write current parameter values into the project's chain.cpp
+    /// If path is NULL, saves in place; otherwise writes a copy of the project
+    /// to that directory. Returns ErrorNoChain if no project is loaded.
+    pub fn vivid_context_save_project(ctx: *mut VividContext, path: *const c_char) -> VividResult;
+
     /// Unload the current project
     pub fn vivid_context_unload_project(ctx: *mut VividContext) -> VividResult;
 
@@ -250,6 +549,34 @@ extern "C" {
     /// Get the loaded project path
     pub fn vivid_context_get_project_path(ctx: *mut VividContext) -> *const c_char;
 
+    /// Get whether the project loaded fully, partially (some operators failed), or not at all
+    pub fn vivid_context_get_load_state(ctx: *mut VividContext) -> VividLoadState;
+
+    /// Get the number of operators that failed to load in a partially loaded project
+    pub fn vivid_context_get_failed_operator_count(ctx: *mut VividContext) -> c_int;
+
+    /// Get the name of a failed operator by index
+    pub fn vivid_context_get_failed_operator(ctx: *mut VividContext, index: c_int) -> *const c_char;
+
+    /// Get the compilation cache directory of the loaded project
+    /// Returns NULL if no project is loaded
+    pub fn vivid_context_get_build_dir(ctx: *mut VividContext) -> *const c_char;
+
+    /// Get the master resolution declared in the loaded project's manifest
+    /// Returns false if no project is loaded or the project doesn't declare one
+    pub fn vivid_context_get_project_resolution(
+        ctx: *mut VividContext,
+        out_width: *mut c_int,
+        out_height: *mut c_int,
+    ) -> bool;
+
+    /// Get the number of GPU features required by the loaded chain's operators
+    /// (e.g. "float32-filterable", "compute"); 0 if no project is loaded
+    pub fn vivid_context_get_required_feature_count(ctx: *mut VividContext) -> c_int;
+
+    /// Get a required GPU feature name by index
+    pub fn vivid_context_get_required_feature(ctx: *mut VividContext, index: c_int) -> *const c_char;
+
     // =========================================================================
     // Frame Processing
     // =========================================================================
@@ -266,6 +593,25 @@ extern "C" {
     /// Reset time and frame counter
     pub fn vivid_context_reset_time(ctx: *mut VividContext);
 
+    /// Set elapsed time without processing a frame (frame counter unchanged)
+    /// Returns ErrorInvalidArgument for negative or non-finite times
+    pub fn vivid_context_set_time(ctx: *mut VividContext, seconds: c_double) -> VividResult;
+
+    /// Get session counters (monotonic since context creation)
+    pub fn vivid_context_get_session_stats(
+        ctx: *mut VividContext,
+        out_stats: *mut VividSessionStats,
+    );
+
+    /// Copy recent frame times (ms spent in vivid_context_process_frame),
+    /// oldest first. Copies up to capacity samples and returns how many are
+    /// available; pass NULL to query the count.
+    pub fn vivid_context_get_frame_times(
+        ctx: *mut VividContext,
+        out_ms: *mut c_float,
+        capacity: c_int,
+    ) -> c_int;
+
     // =========================================================================
     // Resolution Management
     // =========================================================================
@@ -283,6 +629,13 @@ extern "C" {
     /// Get render height
     pub fn vivid_context_get_height(ctx: *mut VividContext) -> c_int;
 
+    /// Render at a fraction of the resolution, bilinearly upscaled to the output
+    /// Does not reallocate output textures; 1.0 renders at full resolution
+    pub fn vivid_context_set_preview_scale(ctx: *mut VividContext, scale: c_float);
+
+    /// Get the current preview scale
+    pub fn vivid_context_get_preview_scale(ctx: *mut VividContext) -> c_float;
+
     // =========================================================================
     // Input Injection
     // =========================================================================
@@ -299,6 +652,10 @@ extern "C" {
     /// Add scroll delta
     pub fn vivid_context_add_scroll(ctx: *mut VividContext, dx: c_float, dy: c_float);
 
+    /// Apply a batch of input events (position, buttons, keys, scroll) atomically
+    /// Events are applied in order, before the next processed frame
+    pub fn vivid_context_apply_input_frame(ctx: *mut VividContext, frame: *const VividInputFrame);
+
     // =========================================================================
     // Chain Access
     // =========================================================================
@@ -312,6 +669,19 @@ extern "C" {
     /// Get the output texture from the chain
     pub fn vivid_context_get_output_texture(ctx: *mut VividContext) -> VividWGPUTexture;
 
+    /// Get the color space of the chain output
+    pub fn vivid_context_get_output_color_space(ctx: *mut VividContext) -> VividColorSpace;
+
+    /// Get how the chain output encodes alpha
+    pub fn vivid_context_get_alpha_mode(ctx: *mut VividContext) -> VividAlphaMode;
+
+    /// Override how the chain output encodes alpha (readback and compositing honor it)
+    pub fn vivid_context_set_alpha_mode(ctx: *mut VividContext, mode: VividAlphaMode);
+
+    /// Check if the chain output has rendered at least one valid frame since the
+    /// last project load (output texture exists and resources have warmed up)
+    pub fn vivid_context_is_output_ready(ctx: *mut VividContext) -> bool;
+
     // =========================================================================
     // Operator Iteration
     // =========================================================================
@@ -334,6 +704,38 @@ extern "C" {
     /// Get the output operator
     pub fn vivid_chain_get_output_operator(chain: *mut VividChain) -> *mut VividOperator;
 
+    /// Get the current scalar output of every value-producing operator
+    /// Writes up to `capacity` entries to `out_values` and returns the total count.
+    /// Pass NULL/0 to query the count. Names are valid until the next frame.
+    pub fn vivid_chain_get_all_values(
+        chain: *mut VividChain,
+        out_values: *mut VividNamedValue,
+        capacity: c_int,
+    ) -> c_int;
+
+    /// Get the chain's structure version
+    /// Incremented whenever operators are added, removed, renamed, reordered, or rewired
+    pub fn vivid_chain_get_version(chain: *mut VividChain) -> u64;
+
+    /// Get the chain's parameter version
+    /// Incremented whenever any operator parameter or bypass state changes, from any client
+    pub fn vivid_chain_get_param_version(chain: *mut VividChain) -> u64;
+
+    /// Rename an operator
+    /// Fails with ErrorInvalidArgument if another operator already has new_name
+    pub fn vivid_chain_rename_operator(
+        chain: *mut VividChain,
+        old_name: *const c_char,
+        new_name: *const c_char,
+    ) -> VividResult;
+
+    /// Move an operator to a new index in the chain's operator list
+    pub fn vivid_chain_move_operator(
+        chain: *mut VividChain,
+        name: *const c_char,
+        new_index: c_int,
+    ) -> VividResult;
+
     // =========================================================================
     // Operator Information
     // =========================================================================
@@ -344,6 +746,13 @@ extern "C" {
     /// Get operator type name
     pub fn vivid_operator_get_type_name(op: *mut VividOperator) -> *const c_char;
 
+    /// Get operator category (e.g., "Generators", "Effects")
+    pub fn vivid_operator_get_category(op: *mut VividOperator) -> *const c_char;
+
+    /// Get operator help text (what the operator does)
+    /// Returns NULL if the operator has no help text
+    pub fn vivid_operator_get_help(op: *mut VividOperator) -> *const c_char;
+
     /// Get operator output kind
     pub fn vivid_operator_get_output_kind(op: *mut VividOperator) -> VividOutputKind;
 
@@ -353,6 +762,34 @@ extern "C" {
     /// Set operator bypass state
     pub fn vivid_operator_set_bypassed(op: *mut VividOperator, bypassed: bool);
 
+    /// Get a counter incremented every time the bypass state changes (from any client)
+    pub fn vivid_operator_get_bypass_version(op: *mut VividOperator) -> u64;
+
+    /// Render this operator at a fraction of the chain resolution, in (0, 1]
+    /// Returns ErrorInvalidArgument for scales outside that range
+    pub fn vivid_operator_set_resolution_scale(
+        op: *mut VividOperator,
+        scale: c_float,
+    ) -> VividResult;
+
+    /// Get the operator's resolution scale (1.0 = chain resolution)
+    pub fn vivid_operator_get_resolution_scale(op: *mut VividOperator) -> c_float;
+
+    /// Get the runtime error that isolated this operator
+    /// Returns NULL if the operator's last process call succeeded
+    pub fn vivid_operator_get_runtime_error(op: *mut VividOperator) -> *const c_char;
+
+    /// Get the index at which the scheduler runs this operator each frame
+    /// Returns -1 if the operator isn't scheduled (e.g. orphaned)
+    pub fn vivid_operator_get_exec_order(op: *mut VividOperator) -> c_int;
+
+    /// Get the source location where the operator was declared
+    /// Returns false if the operator has no recorded source location
+    pub fn vivid_operator_get_source_location(
+        op: *mut VividOperator,
+        out_loc: *mut VividSourceLocation,
+    ) -> bool;
+
     // =========================================================================
     // Operator Outputs (Textures)
     // =========================================================================
@@ -372,6 +809,14 @@ extern "C" {
     /// Get operator output value
     pub fn vivid_operator_get_output_value(op: *mut VividOperator) -> c_float;
 
+    /// Get operator output array (for ValueArray operators)
+    /// Returns NULL for other operators. The data is owned by the operator and only
+    /// valid until its next process call.
+    pub fn vivid_operator_get_output_value_array(
+        op: *mut VividOperator,
+        out_count: *mut c_int,
+    ) -> *const c_float;
+
     // =========================================================================
     // Operator Parameters
     // =========================================================================
@@ -386,6 +831,24 @@ extern "C" {
         out_decl: *mut VividParamDecl,
     ) -> bool;
 
+    /// Get the UI group/section of a parameter by index
+    /// Returns NULL if the parameter is ungrouped
+    pub fn vivid_operator_get_param_group(op: *mut VividOperator, index: c_int) -> *const c_char;
+
+    /// Get the help text of a parameter by index
+    /// Returns NULL if the parameter has no help text
+    pub fn vivid_operator_get_param_help(op: *mut VividOperator, index: c_int) -> *const c_char;
+
+    /// Get the integer values of an enum parameter's options (parallel to enum_labels)
+    /// Writes up to `capacity` values and returns the total count, or 0 if the
+    /// parameter isn't an enum or its values are implicit (index == value)
+    pub fn vivid_operator_get_param_enum_values(
+        op: *mut VividOperator,
+        index: c_int,
+        out_values: *mut c_int,
+        capacity: c_int,
+    ) -> c_int;
+
     /// Get parameter value
     pub fn vivid_operator_get_param(
         op: *mut VividOperator,
@@ -393,6 +856,58 @@ extern "C" {
         out_value: *mut c_float,
     ) -> bool;
 
+    /// Get the value a parameter had when the project was loaded (as written in
+    /// chain.cpp), ignoring runtime edits since then
+    pub fn vivid_operator_get_saved_param(
+        op: *mut VividOperator,
+        name: *const c_char,
+        out_value: *mut c_float,
+    ) -> bool;
+
+    /// Get the string value a String/FilePath parameter had when the project was
+    /// loaded. Returns NULL for unknown or non-string parameters
+    pub fn vivid_operator_get_saved_param_string(
+        op: *mut VividOperator,
+        name: *const c_char,
+    ) -> *const c_char;
+
+    /// Drive a parameter from an expression evaluated every frame
+    /// (e.g. "brightness.value * 2"). Returns ErrorCompileFailed for invalid
+    /// expressions, with details in vivid_get_last_error.
+    pub fn vivid_operator_set_param_expression(
+        op: *mut VividOperator,
+        name: *const c_char,
+        expression: *const c_char,
+    ) -> VividResult;
+
+    /// Remove a parameter's expression, keeping its last evaluated value
+    pub fn vivid_operator_clear_param_expression(
+        op: *mut VividOperator,
+        name: *const c_char,
+    ) -> VividResult;
+
+    /// Get the expression driving a parameter
+    /// Returns NULL if the parameter has a static value
+    pub fn vivid_operator_get_param_expression(
+        op: *mut VividOperator,
+        name: *const c_char,
+    ) -> *const c_char;
+
+    /// Get a parameter's change counter, bumped whenever its value changes
+    /// (from any source, including expressions, MIDI and automation)
+    /// Counters start at 1; returns 0 if the parameter doesn't exist
+    pub fn vivid_operator_get_param_generation(
+        op: *mut VividOperator,
+        name: *const c_char,
+    ) -> u64;
+
+    /// Get what drives a parameter's value
+    /// Returns Static for unbound or unknown parameters
+    pub fn vivid_operator_get_param_binding(
+        op: *mut VividOperator,
+        name: *const c_char,
+    ) -> VividParamBinding;
+
     /// Set parameter value
     pub fn vivid_operator_set_param(
         op: *mut VividOperator,
@@ -400,6 +915,20 @@ extern "C" {
         value: *const c_float,
     ) -> bool;
 
+    /// Get parameter value by declaration index (no name lookup)
+    pub fn vivid_operator_get_param_indexed(
+        op: *mut VividOperator,
+        index: c_int,
+        out_value: *mut c_float,
+    ) -> bool;
+
+    /// Set parameter value by declaration index (no name lookup)
+    pub fn vivid_operator_set_param_indexed(
+        op: *mut VividOperator,
+        index: c_int,
+        value: *const c_float,
+    ) -> bool;
+
     /// Get parameter string value
     pub fn vivid_operator_get_param_string(
         op: *mut VividOperator,
@@ -426,6 +955,13 @@ extern "C" {
     /// Get input name/label
     pub fn vivid_operator_get_input_name(op: *mut VividOperator, index: c_int) -> *const c_char;
 
+    /// Get what the operator substitutes when an input is unconnected
+    /// Returns None for out-of-range indices
+    pub fn vivid_operator_get_input_default(
+        op: *mut VividOperator,
+        index: c_int,
+    ) -> VividInputDefault;
+
     // =========================================================================
     // Operator Registry
     // =========================================================================
@@ -439,6 +975,15 @@ extern "C" {
     /// Get operator category by index
     pub fn vivid_registry_get_operator_category(index: c_int) -> *const c_char;
 
+    /// Get operator help text by index
+    /// Returns NULL if the operator type has no help text
+    pub fn vivid_registry_get_operator_help(index: c_int) -> *const c_char;
+
+    /// Get the coarse instantiation/per-frame cost of an operator type by name
+    /// Returns 0 (light), 1 (medium), 2 (heavy), or -1 if the type is unknown
+    /// or has no cost metadata
+    pub fn vivid_registry_get_operator_cost(type_name: *const c_char) -> c_int;
+
     // =========================================================================
     // Snapshot/Capture
     // =========================================================================
@@ -449,12 +994,85 @@ extern "C" {
         path: *const c_char,
     ) -> VividResult;
 
+    /// Capture current output as PNG-encoded bytes in memory
+    /// If max_dimension > 0, the image is downscaled so neither side exceeds it.
+    /// The returned buffer must be released with vivid_free_buffer.
+    pub fn vivid_context_capture_snapshot_png(
+        ctx: *mut VividContext,
+        max_dimension: c_int,
+        out_data: *mut *mut u8,
+        out_size: *mut usize,
+    ) -> VividResult;
+
+    /// Read back the current output as RGBA8 pixels (sRGB, straight alpha)
+    /// Rows are tightly packed (width * 4 bytes); the GPU's row padding is
+    /// stripped during the copy. The returned buffer must be released with
+    /// vivid_free_buffer.
+    pub fn vivid_context_read_output_pixels(
+        ctx: *mut VividContext,
+        out_width: *mut u32,
+        out_height: *mut u32,
+        out_data: *mut *mut u8,
+        out_size: *mut usize,
+    ) -> VividResult;
+
+    /// Read back the current output into a caller-owned buffer
+    /// Same layout as vivid_context_read_output_pixels. Always writes the output
+    /// size; with data NULL nothing is copied, so this doubles as a size query.
+    /// Returns ErrorInvalidArgument if capacity < width * height * 4.
+    pub fn vivid_context_read_output_pixels_into(
+        ctx: *mut VividContext,
+        data: *mut u8,
+        capacity: usize,
+        out_width: *mut u32,
+        out_height: *mut u32,
+    ) -> VividResult;
+
+    /// Free a buffer allocated by vivid (e.g. from vivid_context_capture_snapshot_png)
+    pub fn vivid_free_buffer(data: *mut u8);
+
+    /// Compute a fast hash of the current output texture's pixels
+    /// Returns false if there is no output texture
+    pub fn vivid_context_get_output_hash(ctx: *mut VividContext, out_hash: *mut u64) -> bool;
+
     /// Capture operator output to a PNG file
     pub fn vivid_operator_capture_snapshot(
         op: *mut VividOperator,
         path: *const c_char,
     ) -> VividResult;
 
+    // =========================================================================
+    // Export
+    // =========================================================================
+
+    /// Get the number of known bundle target platforms
+    pub fn vivid_get_bundle_target_count() -> c_int;
+
+    /// Get a bundle target by index
+    /// Returns false if index is out of range
+    pub fn vivid_get_bundle_target(index: c_int, out_target: *mut VividBundleTarget) -> bool;
+
+    /// Bundle a project as a standalone runnable app, in process (no CLI needed)
+    /// Touches no context, so it may run on any thread while rendering continues
+    /// On success, out_path receives the bundle path (valid until the next export
+    /// on the same thread)
+    pub fn vivid_export_standalone(
+        options: *const VividExportOptions,
+        out_path: *mut *const c_char,
+    ) -> VividResult;
+
+    // =========================================================================
+    // Example Projects
+    // =========================================================================
+
+    /// Get the number of example projects found under the configured vivid root
+    /// Requires vivid_configure_asset_paths to have been called
+    pub fn vivid_get_example_project_count() -> c_int;
+
+    /// Get an example project by index
+    /// Returns false if index is out of range
+    pub fn vivid_get_example_project(index: c_int, out_project: *mut VividExampleProject) -> bool;
+
     // =========================================================================
     // Version Information
     // =========================================================================
@@ -489,6 +1107,8 @@ mod tests {
     #[test]
     fn test_struct_sizes() {
         // Ensure structs have expected sizes for FFI compatibility
-        assert_eq!(std::mem::size_of::<VividContextConfig>(), 12);
+        assert_eq!(std::mem::size_of::<VividContextConfig>(), 20);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(std::mem::size_of::<VividParamDecl>(), 88);
     }
 }
@@ -0,0 +1,91 @@
+//! Asset references from operator file parameters
+
+use std::path::{Path, PathBuf};
+
+/// Broad category of an asset file, inferred from its extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetKind {
+    Image,
+    Video,
+    Audio,
+    Model,
+    Font,
+    Shader,
+    Other,
+}
+
+impl AssetKind {
+    /// Infer the asset kind from a file path's extension
+    pub fn from_path(path: &Path) -> Self {
+        let ext = path
+            .extension()
+            .map(|e| e.to_string_lossy().to_ascii_lowercase())
+            .unwrap_or_default();
+
+        match ext.as_str() {
+            "png" | "jpg" | "jpeg" | "gif" | "bmp" | "tga" | "tif" | "tiff" | "hdr" | "exr" => {
+                AssetKind::Image
+            }
+            "mp4" | "mov" | "m4v" | "avi" | "mkv" | "webm" | "hap" => AssetKind::Video,
+            "wav" | "mp3" | "ogg" | "flac" | "aiff" | "aif" | "m4a" => AssetKind::Audio,
+            "obj" | "gltf" | "glb" | "fbx" | "ply" | "stl" => AssetKind::Model,
+            "ttf" | "otf" => AssetKind::Font,
+            "wgsl" | "glsl" | "frag" | "vert" => AssetKind::Shader,
+            _ => AssetKind::Other,
+        }
+    }
+}
+
+/// Resolve a stored file path against the project root
+///
+/// Expands a leading `~` to the home directory, normalizes `\` separators on
+/// non-Windows platforms, joins relative paths onto `project_root`, and leaves
+/// absolute paths alone.
+pub fn resolve_asset_path(raw: &str, project_root: &Path) -> PathBuf {
+    let normalized = if cfg!(windows) {
+        raw.to_string()
+    } else {
+        raw.replace('\\', "/")
+    };
+
+    let expanded = match normalized.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') || rest.starts_with('\\') => {
+            match home_dir() {
+                Some(home) => home.join(rest.trim_start_matches(['/', '\\'])),
+                None => PathBuf::from(&normalized),
+            }
+        }
+        _ => PathBuf::from(&normalized),
+    };
+
+    if expanded.is_absolute() {
+        expanded
+    } else {
+        project_root.join(expanded)
+    }
+}
+
+/// The current user's home directory
+fn home_dir() -> Option<PathBuf> {
+    let var = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+    std::env::var_os(var)
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+/// A file referenced by an operator's `FilePath` parameter
+#[derive(Debug, Clone)]
+pub struct AssetRef {
+    /// Path as stored in the parameter
+    pub path: PathBuf,
+    /// Path resolved against the project root (same as `path` when unresolved)
+    pub resolved: PathBuf,
+    /// Asset category inferred from the extension
+    pub kind: AssetKind,
+    /// Whether the resolved file exists on disk
+    pub exists: bool,
+    /// Name of the operator that references the file
+    pub referenced_by: String,
+    /// Name of the parameter holding the path
+    pub param: String,
+}
@@ -1,17 +1,51 @@
 //! Chain management for vivid
 
-use std::ffi::CString;
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::CStr;
+use std::path::{Path, PathBuf};
 
-use crate::operator::Operator;
+use crate::asset::{resolve_asset_path, AssetKind, AssetRef};
+use crate::error::{c_string_arg, check_result, to_c_string, Error, Result};
+use crate::operator::{Operator, OutputKind, ParamType};
+use crate::snapshot::{ParamPatch, ParamSnapshot};
+
+/// Node weight in `Chain::to_petgraph()`
+#[cfg(feature = "petgraph")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorNode {
+    pub name: String,
+    pub type_name: String,
+    pub output_kind: OutputKind,
+    pub bypassed: bool,
+}
+
+/// Edge weight in `Chain::to_petgraph()`, pointing from source to consumer
+#[cfg(feature = "petgraph")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputEdge {
+    /// Name of the consumer's input slot
+    pub input: String,
+}
 
 /// A reference to a vivid chain
 ///
 /// The chain is owned by the context and provides access to operators.
-/// This is a lightweight handle that borrows from the context.
+/// This is a lightweight handle that borrows from the context. Clones copy the
+/// pointer and are only valid until the project is reloaded or the context is
+/// destroyed.
+#[derive(Clone)]
 pub struct Chain {
     ptr: *mut vivid_sys::VividChain,
 }
 
+impl std::fmt::Debug for Chain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Chain")
+            .field("operator_count", &self.operator_count())
+            .finish()
+    }
+}
+
 impl Chain {
     /// Create a chain handle from a raw pointer
     ///
@@ -42,9 +76,10 @@ impl Chain {
 
     /// Get an operator by name
     ///
-    /// Returns `None` if not found.
+    /// Returns `None` if not found, or if `name` contains a NUL byte (see
+    /// `last_error()`).
     pub fn operator_by_name(&self, name: &str) -> Option<Operator> {
-        let c_name = CString::new(name).ok()?;
+        let c_name = to_c_string("Operator name", name)?;
         let ptr = unsafe { vivid_sys::vivid_chain_get_operator_by_name(self.ptr, c_name.as_ptr()) };
         if ptr.is_null() {
             None
@@ -53,6 +88,20 @@ impl Chain {
         }
     }
 
+    /// Get the names of all operators, in index order
+    pub fn operator_names(&self) -> Vec<String> {
+        self.operators().map(|op| op.name()).collect()
+    }
+
+    /// Get the index of the operator with the given name
+    ///
+    /// The index matches `operator_by_index()` and the order of `operators()`.
+    /// Returns `None` if not found.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        (0..self.operator_count())
+            .find(|&index| self.operator_by_index(index).is_some_and(|op| op.name() == name))
+    }
+
     /// Get the output operator
     ///
     /// Returns `None` if no output is set.
@@ -65,13 +114,352 @@ impl Chain {
         }
     }
 
+    /// List the files referenced by `FilePath` parameters across all operators
+    ///
+    /// Missing files load as silent black nodes, so this lets the IDE warn before
+    /// export. Paths are checked as stored; use [`Chain::referenced_assets_in`] to
+    /// resolve project-relative paths first. Empty paths are skipped.
+    pub fn referenced_assets(&self) -> Vec<AssetRef> {
+        self.collect_assets(None)
+    }
+
+    /// Like [`Chain::referenced_assets`], but resolves each path against the
+    /// project root before checking that it exists
+    pub fn referenced_assets_in(&self, project_root: &Path) -> Vec<AssetRef> {
+        self.collect_assets(Some(project_root))
+    }
+
+    fn collect_assets(&self, project_root: Option<&Path>) -> Vec<AssetRef> {
+        let mut assets = Vec::new();
+
+        for op in self.operators() {
+            let op_name = op.name();
+            for decl in op.params() {
+                if decl.param_type != ParamType::FilePath {
+                    continue;
+                }
+                let Some(value) = op.get_param_string(&decl.name) else {
+                    continue;
+                };
+                if value.is_empty() {
+                    continue;
+                }
+
+                let resolved = match project_root {
+                    Some(root) => resolve_asset_path(&value, root),
+                    None => PathBuf::from(&value),
+                };
+                let path = PathBuf::from(value);
+                assets.push(AssetRef {
+                    kind: AssetKind::from_path(&path),
+                    exists: resolved.exists(),
+                    path,
+                    resolved,
+                    referenced_by: op_name.clone(),
+                    param: decl.name,
+                });
+            }
+        }
+
+        assets
+    }
+
+    /// Find the operator declared at a given source line
+    ///
+    /// `file` is matched component-wise against each operator's recorded source
+    /// file, so either an absolute path or a project-relative path (e.g.
+    /// `"chain.cpp"`) works. When several operators are declared on the same
+    /// line, the first one in chain index order is returned.
+    pub fn operator_at_source_line(&self, file: &str, line: u32) -> Option<Operator> {
+        let file = Path::new(file);
+        self.operators().find(|op| {
+            op.source_location().is_some_and(|loc| {
+                let loc_file = Path::new(&loc.file);
+                loc.line == line && (loc_file.ends_with(file) || file.ends_with(loc_file))
+            })
+        })
+    }
+
+    /// Get the current scalar output of every value-producing operator
+    ///
+    /// Reads all values in a single FFI call, so they are consistent within a frame.
+    /// Returns `(operator name, value)` pairs in chain index order.
+    pub fn output_values(&self) -> Vec<(String, f32)> {
+        let count = unsafe {
+            vivid_sys::vivid_chain_get_all_values(self.ptr, std::ptr::null_mut(), 0)
+        };
+        if count <= 0 {
+            return Vec::new();
+        }
+
+        let mut raw = vec![
+            vivid_sys::VividNamedValue {
+                name: std::ptr::null(),
+                value: 0.0,
+            };
+            count as usize
+        ];
+        let written = unsafe {
+            vivid_sys::vivid_chain_get_all_values(self.ptr, raw.as_mut_ptr(), count)
+        };
+        raw.truncate(written.clamp(0, count) as usize);
+
+        raw.into_iter()
+            .filter(|v| !v.name.is_null())
+            .map(|v| {
+                let name = unsafe { CStr::from_ptr(v.name).to_string_lossy().into_owned() };
+                (name, v.value)
+            })
+            .collect()
+    }
+
+    /// Get the chain's structure version
+    ///
+    /// Incremented by vivid-core on every structural change (operators added,
+    /// removed, renamed, reordered, or rewired), whoever made it. Parameter edits
+    /// don't count. Compare against a stored value to tell whether cached views of
+    /// the graph are stale.
+    pub fn version(&self) -> u64 {
+        unsafe { vivid_sys::vivid_chain_get_version(self.ptr) }
+    }
+
+    /// Get the chain's parameter version
+    ///
+    /// Incremented whenever any operator's parameters or bypass state change,
+    /// including edits made in the visualizer. Together with `version()` this
+    /// lets a UI poll cheaply and refetch only what changed.
+    pub fn param_version(&self) -> u64 {
+        unsafe { vivid_sys::vivid_chain_get_param_version(self.ptr) }
+    }
+
+    /// Rename an operator
+    ///
+    /// Connections follow the operator, so consumers keep their inputs. Fails with
+    /// `Error::InvalidArgument` if the new name is empty or already taken, and
+    /// `Error::OperatorNotFound` if there is no operator named `old_name`.
+    /// Existing `Operator` handles stay valid but report the new name.
+    pub fn rename_operator(&mut self, old_name: &str, new_name: &str) -> Result<()> {
+        if new_name.is_empty() {
+            return Err(Error::InvalidArgument("Operator name cannot be empty".into()));
+        }
+        if old_name != new_name && self.operator_by_name(new_name).is_some() {
+            return Err(Error::InvalidArgument(format!(
+                "An operator named '{}' already exists",
+                new_name
+            )));
+        }
+
+        let c_old = c_string_arg("Operator name", old_name)?;
+        let c_new = c_string_arg("Operator name", new_name)?;
+
+        let result = unsafe {
+            vivid_sys::vivid_chain_rename_operator(self.ptr, c_old.as_ptr(), c_new.as_ptr())
+        };
+        check_result(result)
+    }
+
+    /// Move an operator to a new position in the chain
+    ///
+    /// Out-of-range indices clamp to the end of the chain. Inputs are connected
+    /// by reference, not by index, so moving an operator never breaks its
+    /// connections. Reordering changes the index order seen by `operators()` and
+    /// `operator_by_index()`, and the relative evaluation order of operators that
+    /// don't depend on each other; an operator is always evaluated after its inputs.
+    pub fn move_operator(&mut self, name: &str, new_index: usize) -> Result<()> {
+        let c_name = c_string_arg("Operator name", name)?;
+        let new_index = new_index.min(self.operator_count().saturating_sub(1));
+
+        let result = unsafe {
+            vivid_sys::vivid_chain_move_operator(self.ptr, c_name.as_ptr(), new_index as i32)
+        };
+        check_result(result)
+    }
+
+    /// Capture every operator's numeric parameter values
+    ///
+    /// String and file-path parameters are skipped. Diff two snapshots with
+    /// `ParamSnapshot::diff()` to get an undoable `ParamPatch`.
+    pub fn param_snapshot(&self) -> ParamSnapshot {
+        let values = self.operators().map(|op| (op.name(), op.param_values())).collect();
+        ParamSnapshot { values }
+    }
+
+    /// Apply a patch's `after` values, returning how many parameters were set
+    ///
+    /// Changes for operators or parameters that no longer exist (or that
+    /// vivid-core rejects) are skipped; the rest still apply.
+    pub fn apply_patch(&mut self, patch: &ParamPatch) -> usize {
+        let mut by_operator: BTreeMap<&str, BTreeMap<String, [f32; 4]>> = BTreeMap::new();
+        for change in &patch.changes {
+            by_operator
+                .entry(change.operator.as_str())
+                .or_default()
+                .insert(change.param.clone(), change.after);
+        }
+
+        by_operator
+            .into_iter()
+            .filter_map(|(name, values)| {
+                let mut op = self.operator_by_name(name)?;
+                Some(values.len() - op.apply_params(&values).len())
+            })
+            .sum()
+    }
+
     /// Iterate over all operators in the chain
-    pub fn operators(&self) -> OperatorIterator {
+    ///
+    /// Parameter edits on the yielded operators are fine mid-iteration. A
+    /// structural change (see `version()`), e.g. `move_operator()` through a
+    /// cloned `Chain`, ends the iteration early rather than yielding by a stale
+    /// index; collect `operator_names()` first to restructure while walking.
+    pub fn operators(&self) -> OperatorIterator<'_> {
         OperatorIterator {
             chain: self,
             index: 0,
             count: self.operator_count(),
+            version: self.version(),
+        }
+    }
+
+    /// Get all operators of a given type (e.g. every "Blur")
+    ///
+    /// Compares against `Operator::type_name()` exactly. Returns an empty vec if
+    /// nothing matches.
+    pub fn operators_by_type(&self, type_name: &str) -> Vec<Operator> {
+        self.operators()
+            .filter(|op| op.type_name() == type_name)
+            .collect()
+    }
+
+    /// Get all operators ordered so that every input precedes its consumers
+    ///
+    /// Performs a depth-first search over `Operator::input()` links, visiting
+    /// roots in index order so the result is deterministic. Operators reachable
+    /// through inputs but not listed in the chain are included too. Returns
+    /// `Error::CycleDetected` naming an operator on the cycle if the inputs loop.
+    ///
+    /// This is a dependency order, not necessarily the scheduler's actual run
+    /// order; see `Operator::execution_order()` for that.
+    pub fn operators_topological(&self) -> Result<Vec<Operator>> {
+        let mut state = HashMap::new();
+        let mut path = Vec::new();
+        let mut sorted = Vec::with_capacity(self.operator_count());
+        for op in self.operators() {
+            visit_inputs(op, &mut state, &mut path, &mut sorted).map_err(|cycle| {
+                Error::CycleDetected(cycle.into_iter().next().unwrap_or_default())
+            })?;
+        }
+        Ok(sorted)
+    }
+
+    /// Find a cycle in the operator graph's input links
+    ///
+    /// Returns the names of the operators forming the cycle, each one consuming
+    /// the next as an input and the last consuming the first, or `None` if the
+    /// graph is acyclic. Call it after `load_project()` to validate a project before
+    /// walking inputs upstream, which would otherwise loop forever.
+    pub fn find_cycle(&self) -> Option<Vec<String>> {
+        let mut state = HashMap::new();
+        let mut path = Vec::new();
+        let mut sorted = Vec::new();
+        self.operators()
+            .find_map(|op| visit_inputs(op, &mut state, &mut path, &mut sorted).err())
+    }
+
+    /// Render the operator graph as a Graphviz DOT digraph
+    ///
+    /// One node per operator, labeled with its name and type, and one edge per
+    /// connected input (source -> consumer), labeled with the input name.
+    /// Bypassed operators are drawn dashed. Feed the result to `dot -Tpng`.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph chain {\n    rankdir=LR;\n    node [shape=box];\n");
+
+        for op in self.operators() {
+            let style = if op.is_bypassed() { ", style=dashed" } else { "" };
+            dot.push_str(&format!(
+                "    \"{}\" [label=\"{}\\n{}\"{}];\n",
+                dot_escape(&op.name()),
+                dot_escape(&op.name()),
+                dot_escape(&op.type_name()),
+                style
+            ));
         }
+
+        for op in self.operators() {
+            for (input_name, source) in op.input_iter() {
+                dot.push_str(&format!(
+                    "    \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                    dot_escape(&source.name()),
+                    dot_escape(&op.name()),
+                    dot_escape(&input_name)
+                ));
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Build a petgraph of the operator graph for use with its algorithms
+    ///
+    /// Same shape as `to_dot()`: one node per operator in chain order, and one
+    /// directed edge per connected input (source -> consumer). Nodes and edges
+    /// hold owned snapshots, so the graph stays valid after the project reloads.
+    #[cfg(feature = "petgraph")]
+    pub fn to_petgraph(&self) -> petgraph::Graph<OperatorNode, InputEdge> {
+        let mut graph = petgraph::Graph::with_capacity(self.operator_count(), 0);
+        let mut indices = HashMap::new();
+
+        for op in self.operators() {
+            let name = op.name();
+            let index = graph.add_node(OperatorNode {
+                name: name.clone(),
+                type_name: op.type_name(),
+                output_kind: op.output_kind(),
+                bypassed: op.is_bypassed(),
+            });
+            indices.insert(name, index);
+        }
+
+        for op in self.operators() {
+            let consumer = indices[&op.name()];
+            for (input, source) in op.input_iter() {
+                if let Some(&source) = indices.get(&source.name()) {
+                    graph.add_edge(source, consumer, InputEdge { input });
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// Get all operators producing a given kind of output (e.g. every texture)
+    pub fn operators_by_output_kind(&self, kind: OutputKind) -> Vec<Operator> {
+        self.operators()
+            .filter(|op| op.output_kind() == kind)
+            .collect()
+    }
+
+    /// Find parameters whose name contains `query` (case-insensitive)
+    ///
+    /// Returns `(operator name, parameter name)` pairs in chain order, so a search
+    /// like "gamma" can jump to the operator exposing it. An empty query matches
+    /// nothing.
+    pub fn search_params(&self, query: &str) -> Vec<(String, String)> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        self.operators()
+            .flat_map(|op| {
+                let op_name = op.name();
+                op.params()
+                    .into_iter()
+                    .filter(|decl| decl.name.to_lowercase().contains(&query))
+                    .map(move |decl| (op_name.clone(), decl.name))
+            })
+            .collect()
     }
 
     /// Get the raw chain pointer
@@ -80,11 +468,63 @@ impl Chain {
     }
 }
 
+/// Escape a string for use inside a quoted DOT identifier or label
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Three-color DFS mark (absent = unvisited)
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    InProgress,
+    Done,
+}
+
+/// Three-color DFS over input links, shared by topological sort and cycle detection
+///
+/// Pushes each operator onto `sorted` after all of its inputs. On reaching an
+/// operator that is still in progress, returns the names along the cycle,
+/// starting from that operator.
+fn visit_inputs(
+    op: Operator,
+    state: &mut HashMap<*mut vivid_sys::VividOperator, Visit>,
+    path: &mut Vec<*mut vivid_sys::VividOperator>,
+    sorted: &mut Vec<Operator>,
+) -> std::result::Result<(), Vec<String>> {
+    match state.get(&op.as_raw()) {
+        Some(Visit::Done) => return Ok(()),
+        Some(Visit::InProgress) => {
+            let start = path.iter().position(|&p| p == op.as_raw()).unwrap_or(0);
+            let cycle = path[start..]
+                .iter()
+                .map(|&p| Operator::from_raw(p).name())
+                .collect();
+            return Err(cycle);
+        }
+        None => {}
+    }
+
+    state.insert(op.as_raw(), Visit::InProgress);
+    path.push(op.as_raw());
+    for index in 0..op.input_count() {
+        if let Some(input) = op.input(index) {
+            visit_inputs(input, state, path, sorted)?;
+        }
+    }
+    path.pop();
+    state.insert(op.as_raw(), Visit::Done);
+    sorted.push(op);
+    Ok(())
+}
+
 /// Iterator over operators in a chain
+///
+/// Stops early if the chain's structure changes during iteration.
 pub struct OperatorIterator<'a> {
     chain: &'a Chain,
     index: usize,
     count: usize,
+    version: u64,
 }
 
 impl<'a> Iterator for OperatorIterator<'a> {
@@ -94,15 +534,22 @@ impl<'a> Iterator for OperatorIterator<'a> {
         if self.index >= self.count {
             return None;
         }
+        // Indices are only meaningful for the structure we started with
+        if self.chain.version() != self.version {
+            self.index = self.count;
+            return None;
+        }
         let op = self.chain.operator_by_index(self.index);
         self.index += 1;
+        if op.is_none() {
+            self.index = self.count;
+        }
         op
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.count - self.index;
-        (remaining, Some(remaining))
+        (0, Some(self.count - self.index))
     }
 }
 
-impl<'a> ExactSizeIterator for OperatorIterator<'a> {}
+impl<'a> std::iter::FusedIterator for OperatorIterator<'a> {}
@@ -1,16 +1,23 @@
 //! Context management for vivid
 
 use std::ffi::{CStr, CString};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::ptr;
 
 use crate::chain::Chain;
-use crate::error::{check_result, Error, Result};
+use crate::error::{check_result, to_c_string, Error, Result};
+use crate::input::InputFrame;
 
 /// Configure asset search paths before creating a context
 ///
 /// For embedded use: tells vivid where to find shaders, fonts, and other assets.
 /// Call this BEFORE creating a Context when vivid is embedded as a submodule.
+///
+/// This is process-wide setup rather than per-context state: a context resolves
+/// its asset paths when it is created, so contexts that already exist won't see
+/// a later change. `example_projects()` is also found under this root. Pair it
+/// with `Context::set_root_dir()` for hot-reload compiles. Returns
+/// `Error::InvalidArgument` if the path contains a NUL byte.
 pub fn configure_asset_paths<P: AsRef<Path>>(vivid_root: P) -> Result<()> {
     let path_str = vivid_root.as_ref().to_string_lossy();
     let c_path = CString::new(path_str.as_ref())
@@ -20,6 +27,59 @@ pub fn configure_asset_paths<P: AsRef<Path>>(vivid_root: P) -> Result<()> {
     check_result(result)
 }
 
+/// How the chain output encodes its alpha channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlphaMode {
+    /// Alpha is ignored; the output is fully opaque
+    Opaque,
+    /// Color channels are already multiplied by alpha
+    Premultiplied,
+    /// Color channels are independent of alpha
+    Straight,
+}
+
+impl From<vivid_sys::VividAlphaMode> for AlphaMode {
+    fn from(mode: vivid_sys::VividAlphaMode) -> Self {
+        match mode {
+            vivid_sys::VividAlphaMode::Opaque => AlphaMode::Opaque,
+            vivid_sys::VividAlphaMode::Premultiplied => AlphaMode::Premultiplied,
+            vivid_sys::VividAlphaMode::Straight => AlphaMode::Straight,
+        }
+    }
+}
+
+impl From<AlphaMode> for vivid_sys::VividAlphaMode {
+    fn from(mode: AlphaMode) -> Self {
+        match mode {
+            AlphaMode::Opaque => vivid_sys::VividAlphaMode::Opaque,
+            AlphaMode::Premultiplied => vivid_sys::VividAlphaMode::Premultiplied,
+            AlphaMode::Straight => vivid_sys::VividAlphaMode::Straight,
+        }
+    }
+}
+
+/// Texture format of the chain output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorFormat {
+    /// 8 bits per channel, the default
+    #[default]
+    Rgba8Unorm,
+    /// Half-float per channel, for HDR output
+    Rgba16Float,
+    /// 8 bits per channel in BGRA order (matches many swapchains)
+    Bgra8Unorm,
+}
+
+impl From<ColorFormat> for vivid_sys::VividColorFormat {
+    fn from(format: ColorFormat) -> Self {
+        match format {
+            ColorFormat::Rgba8Unorm => vivid_sys::VividColorFormat::Rgba8Unorm,
+            ColorFormat::Rgba16Float => vivid_sys::VividColorFormat::Rgba16Float,
+            ColorFormat::Bgra8Unorm => vivid_sys::VividColorFormat::Bgra8Unorm,
+        }
+    }
+}
+
 /// Configuration for creating a vivid context
 #[derive(Debug, Clone)]
 pub struct ContextConfig {
@@ -29,6 +89,14 @@ pub struct ContextConfig {
     pub height: u32,
     /// Enable WebGPU validation (debug mode)
     pub enable_validation: bool,
+    /// Force how the output's alpha is interpreted (`None` lets the chain decide)
+    pub alpha_mode: Option<AlphaMode>,
+    /// Load projects with every operator bypassed (see `Context::set_safe_mode()`)
+    pub safe_mode: bool,
+    /// MSAA sample count: 1 (off), 2, 4 or 8
+    pub sample_count: u32,
+    /// Texture format of the chain output
+    pub format: ColorFormat,
 }
 
 impl ContextConfig {
@@ -38,6 +106,10 @@ impl ContextConfig {
             width,
             height,
             enable_validation: false,
+            alpha_mode: None,
+            safe_mode: false,
+            sample_count: 1,
+            format: ColorFormat::Rgba8Unorm,
         }
     }
 
@@ -46,6 +118,47 @@ impl ContextConfig {
         self.enable_validation = enable;
         self
     }
+
+    /// Force the output alpha mode
+    pub fn with_alpha_mode(mut self, mode: AlphaMode) -> Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+
+    /// Enable safe mode, for opening projects that crash the renderer
+    pub fn with_safe_mode(mut self, enable: bool) -> Self {
+        self.safe_mode = enable;
+        self
+    }
+
+    /// Set the MSAA sample count (1, 2, 4 or 8; checked at context creation)
+    pub fn with_sample_count(mut self, count: u32) -> Self {
+        self.sample_count = count;
+        self
+    }
+
+    /// Set the output texture format (e.g. `Rgba16Float` for HDR)
+    pub fn with_format(mut self, format: ColorFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    fn to_ffi(&self) -> Result<vivid_sys::VividContextConfig> {
+        if !matches!(self.sample_count, 1 | 2 | 4 | 8) {
+            return Err(Error::InvalidArgument(format!(
+                "Unsupported sample count: {}",
+                self.sample_count
+            )));
+        }
+
+        Ok(vivid_sys::VividContextConfig {
+            width: self.width as i32,
+            height: self.height as i32,
+            enable_validation: self.enable_validation,
+            sample_count: self.sample_count as i32,
+            format: self.format.into(),
+        })
+    }
 }
 
 impl Default for ContextConfig {
@@ -54,6 +167,182 @@ impl Default for ContextConfig {
     }
 }
 
+/// GPU setup step reached while creating a windowed context
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreateStage {
+    /// All steps succeeded
+    Complete,
+    /// Creating the WebGPU instance (backend unavailable)
+    Instance,
+    /// Creating the surface for the native window
+    Surface,
+    /// Requesting a compatible adapter (no suitable GPU or driver)
+    Adapter,
+    /// Requesting the device from the adapter
+    Device,
+}
+
+impl From<vivid_sys::VividCreateStage> for CreateStage {
+    fn from(stage: vivid_sys::VividCreateStage) -> Self {
+        match stage {
+            vivid_sys::VividCreateStage::Complete => CreateStage::Complete,
+            vivid_sys::VividCreateStage::Instance => CreateStage::Instance,
+            vivid_sys::VividCreateStage::Surface => CreateStage::Surface,
+            vivid_sys::VividCreateStage::Adapter => CreateStage::Adapter,
+            vivid_sys::VividCreateStage::Device => CreateStage::Device,
+        }
+    }
+}
+
+impl std::fmt::Display for CreateStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            CreateStage::Complete => "complete",
+            CreateStage::Instance => "instance",
+            CreateStage::Surface => "surface",
+            CreateStage::Adapter => "adapter",
+            CreateStage::Device => "device",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Details about GPU setup when creating a windowed context
+#[derive(Debug, Clone)]
+pub struct ContextDiagnostics {
+    /// Step that failed, or `Complete` on success
+    pub stage: CreateStage,
+    /// Graphics backend that was tried (e.g. "Metal", "Vulkan", "D3D12")
+    pub backend: Option<String>,
+    /// Name of the adapter that was selected
+    pub adapter_name: Option<String>,
+    /// Driver name/version reported by the adapter
+    pub driver: Option<String>,
+    /// Error message from the failing step
+    pub message: Option<String>,
+}
+
+impl ContextDiagnostics {
+    fn from_ffi(diag: &vivid_sys::VividContextDiagnostics) -> Self {
+        let string = |ptr: *const std::os::raw::c_char| {
+            if ptr.is_null() {
+                None
+            } else {
+                Some(unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned())
+            }
+        };
+
+        Self {
+            stage: diag.stage.into(),
+            backend: string(diag.backend),
+            adapter_name: string(diag.adapter_name),
+            driver: string(diag.driver),
+            message: string(diag.message),
+        }
+    }
+}
+
+impl std::fmt::Display for ContextDiagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} stage", self.stage)?;
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+        if let Some(backend) = &self.backend {
+            write!(f, " (backend: {})", backend)?;
+        }
+        if let Some(adapter) = &self.adapter_name {
+            write!(f, " (adapter: {})", adapter)?;
+        }
+        if let Some(driver) = &self.driver {
+            write!(f, " (driver: {})", driver)?;
+        }
+        Ok(())
+    }
+}
+
+/// Options for exporting a standalone app
+#[derive(Debug, Clone, Default)]
+pub struct BundleOptions {
+    /// Project to bundle (required by the free `export_standalone()`;
+    /// `Context::export_standalone()` defaults it to the loaded project)
+    pub project_path: Option<PathBuf>,
+    /// Where to write the bundle (defaults to the project directory)
+    pub output_dir: Option<PathBuf>,
+    /// Name of the app (defaults to the project name)
+    pub app_name: Option<String>,
+    /// Target platform (defaults to the host platform)
+    pub platform: Option<String>,
+}
+
+impl BundleOptions {
+    /// Create options that bundle the loaded project with defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bundle a specific project instead of the loaded one
+    pub fn with_project_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.project_path = Some(path.into());
+        self
+    }
+
+    /// Set the output directory
+    pub fn with_output_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.output_dir = Some(dir.into());
+        self
+    }
+
+    /// Set the app name
+    pub fn with_app_name(mut self, name: impl Into<String>) -> Self {
+        self.app_name = Some(name.into());
+        self
+    }
+
+    /// Set the target platform
+    pub fn with_platform(mut self, platform: impl Into<String>) -> Self {
+        self.platform = Some(platform.into());
+        self
+    }
+}
+
+/// A platform that projects can be bundled for
+#[derive(Debug, Clone)]
+pub struct BundleTarget {
+    /// Identifier to pass as `BundleOptions::platform` (e.g. "macos", "windows")
+    pub id: String,
+    /// Human-readable name for the export dialog
+    pub display_name: String,
+    /// Whether this host can bundle for the target (cross-bundling is limited)
+    pub available: bool,
+    /// Why the target is unavailable, for display next to the disabled option
+    pub unavailable_reason: Option<String>,
+}
+
+/// Counters accumulated over the lifetime of a context
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SessionStats {
+    /// Frames processed since the context was created
+    pub total_frames: u64,
+    /// Wall-clock seconds spent processing and rendering frames
+    pub total_render_seconds: f64,
+    /// Seconds since the context was created
+    pub uptime_seconds: f64,
+}
+
+/// An example project bundled with vivid
+#[derive(Debug, Clone)]
+pub struct ExampleProject {
+    /// Path relative to the `projects/` directory, unique per example
+    pub id: String,
+    /// Display name from the project's manifest (falls back to the directory name)
+    pub name: String,
+    /// Description from the project's manifest, if any
+    pub description: Option<String>,
+    /// Project directory, suitable for `Context::load_project()`
+    pub path: PathBuf,
+}
+
 /// Compilation status information
 #[derive(Debug, Clone)]
 pub struct CompileStatus {
@@ -67,18 +356,198 @@ pub struct CompileStatus {
     pub error_column: Option<u32>,
 }
 
+/// A single compile error location and message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompileDiagnostic {
+    pub message: String,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+}
+
+impl CompileStatus {
+    /// Get the errors to show as editor markers
+    ///
+    /// Always empty when compilation succeeded, so a host can replace its markers
+    /// with this list on every status update and never keep stale ones.
+    pub fn diagnostics(&self) -> Vec<CompileDiagnostic> {
+        if self.success {
+            return Vec::new();
+        }
+        vec![CompileDiagnostic {
+            message: self.message.clone().unwrap_or_else(|| "Compilation failed".into()),
+            line: self.error_line,
+            column: self.error_column,
+        }]
+    }
+
+    fn from_ffi(status: &vivid_sys::VividCompileStatus) -> Self {
+        let message = if status.message.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(status.message).to_string_lossy().into_owned() })
+        };
+
+        let error_line = if status.error_line > 0 {
+            Some(status.error_line as u32)
+        } else {
+            None
+        };
+
+        let error_column = if status.error_column > 0 {
+            Some(status.error_column as u32)
+        } else {
+            None
+        };
+
+        Self {
+            success: status.success,
+            message,
+            error_line,
+            error_column,
+        }
+    }
+}
+
+/// Color space of the chain output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Gamma-encoded sRGB
+    Srgb,
+    /// Linear-light values with sRGB primaries
+    LinearSrgb,
+    /// Gamma-encoded Display P3
+    DisplayP3,
+    /// Not reported by the chain; treat as sRGB
+    Unknown,
+}
+
+impl From<vivid_sys::VividColorSpace> for ColorSpace {
+    fn from(space: vivid_sys::VividColorSpace) -> Self {
+        match space {
+            vivid_sys::VividColorSpace::Unknown => ColorSpace::Unknown,
+            vivid_sys::VividColorSpace::Srgb => ColorSpace::Srgb,
+            vivid_sys::VividColorSpace::LinearSrgb => ColorSpace::LinearSrgb,
+            vivid_sys::VividColorSpace::DisplayP3 => ColorSpace::DisplayP3,
+        }
+    }
+}
+
+impl ColorSpace {
+    /// Whether pixel values need gamma encoding before being written as 8-bit sRGB
+    /// (e.g. PNG). `Unknown` is assumed to already be sRGB.
+    pub fn is_linear(&self) -> bool {
+        matches!(self, ColorSpace::LinearSrgb)
+    }
+}
+
+/// Encoding for `Context::capture_snapshot_as()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Lossless, keeps alpha
+    Png,
+    /// Lossy, drops alpha; `quality` is clamped to 1..=100
+    Jpeg { quality: u8 },
+    /// Lossless WebP, keeps alpha
+    WebP,
+}
+
+/// Animation format for `Context::capture_loop()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopFormat {
+    /// Animated GIF, quantized per frame to `palette_size` colors (2..=256);
+    /// alpha is dropped
+    Gif { palette_size: u16 },
+    /// Animated PNG, lossless with alpha
+    Apng,
+}
+
+/// How completely the current project loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadState {
+    /// No project is loaded
+    Empty,
+    /// The project loaded but some operators failed (output may be broken)
+    PartiallyLoaded {
+        /// Names of the operators that failed
+        failed_ops: Vec<String>,
+    },
+    /// Every operator loaded successfully
+    FullyLoaded,
+}
+
+/// Boxed compile-status callback, double-boxed so the C side gets a thin pointer
+type CompileCallback = Box<dyn FnMut(CompileStatus) + Send>;
+
+/// C trampoline that forwards compile notifications to the registered closure
+///
+/// Panics are caught here since unwinding across the FFI boundary is undefined.
+unsafe extern "C" fn compile_callback_trampoline(
+    status: *const vivid_sys::VividCompileStatus,
+    user_data: *mut std::ffi::c_void,
+) {
+    if status.is_null() || user_data.is_null() {
+        return;
+    }
+
+    let callback = &mut *(user_data as *mut CompileCallback);
+    let status = CompileStatus::from_ffi(&*status);
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(status)));
+}
+
+/// Boxed operator-error callback, double-boxed like `CompileCallback`
+type OperatorErrorCallback = Box<dyn FnMut(&str, &str) + Send>;
+
+/// C trampoline that forwards operator runtime failures to the registered closure
+unsafe extern "C" fn operator_error_trampoline(
+    op_name: *const std::os::raw::c_char,
+    message: *const std::os::raw::c_char,
+    user_data: *mut std::ffi::c_void,
+) {
+    if op_name.is_null() || user_data.is_null() {
+        return;
+    }
+
+    let callback = &mut *(user_data as *mut OperatorErrorCallback);
+    let op_name = CStr::from_ptr(op_name).to_string_lossy();
+    let message = if message.is_null() {
+        Default::default()
+    } else {
+        CStr::from_ptr(message).to_string_lossy()
+    };
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(&op_name, &message)));
+}
+
 /// A vivid context for processing chains
 ///
 /// The context owns the chain and manages the lifecycle of operators.
 /// It accepts an external wgpu device/queue for rendering.
 pub struct Context {
     ptr: *mut vivid_sys::VividContext,
+    compile_callback: Option<Box<CompileCallback>>,
+    operator_error_callback: Option<Box<OperatorErrorCallback>>,
 }
 
 // Context can be sent between threads (vivid is single-threaded but the handle is safe)
 unsafe impl Send for Context {}
 
 impl Context {
+    /// Wrap a freshly created context and apply the config options that aren't
+    /// part of the C creation struct
+    fn from_created(ptr: *mut vivid_sys::VividContext, config: &ContextConfig) -> Self {
+        let mut ctx = Self {
+            ptr,
+            compile_callback: None,
+            operator_error_callback: None,
+        };
+        if let Some(mode) = config.alpha_mode {
+            ctx.set_output_alpha_mode(mode);
+        }
+        if config.safe_mode {
+            ctx.set_safe_mode(true);
+        }
+        ctx
+    }
+
     /// Create a new context with a native window handle
     ///
     /// This creates a context that owns all GPU resources (instance, device, surface).
@@ -97,11 +566,7 @@ impl Context {
         native_window: *mut std::ffi::c_void,
         config: ContextConfig,
     ) -> Result<Self> {
-        let ffi_config = vivid_sys::VividContextConfig {
-            width: config.width as i32,
-            height: config.height as i32,
-            enable_validation: config.enable_validation,
-        };
+        let ffi_config = config.to_ffi()?;
 
         let mut ctx_ptr: *mut vivid_sys::VividContext = ptr::null_mut();
 
@@ -117,7 +582,56 @@ impl Context {
             return Err(Error::Internal("Context pointer is null".into()));
         }
 
-        Ok(Self { ptr: ctx_ptr })
+        Ok(Self::from_created(ctx_ptr, &config))
+    }
+
+    /// Create a new context with a native window handle, reporting GPU setup details
+    ///
+    /// Behaves like [`Context::with_window`], but on failure returns
+    /// [`Error::ContextCreation`] describing which stage failed (instance, surface,
+    /// adapter, device) and the backend/adapter/driver that was tried. On success the
+    /// diagnostics are returned alongside the context for logging.
+    ///
+    /// # Safety
+    ///
+    /// The native_window must be a valid platform window handle that remains valid
+    /// for the lifetime of this context.
+    pub unsafe fn with_window_diagnostics(
+        native_window: *mut std::ffi::c_void,
+        config: ContextConfig,
+    ) -> Result<(Self, ContextDiagnostics)> {
+        let ffi_config = config.to_ffi()?;
+
+        let mut ctx_ptr: *mut vivid_sys::VividContext = ptr::null_mut();
+        let mut ffi_diag = vivid_sys::VividContextDiagnostics {
+            stage: vivid_sys::VividCreateStage::Instance,
+            backend: ptr::null(),
+            adapter_name: ptr::null(),
+            driver: ptr::null(),
+            message: ptr::null(),
+        };
+
+        let result = vivid_sys::vivid_context_create_with_window_ex(
+            native_window,
+            &ffi_config,
+            &mut ctx_ptr,
+            &mut ffi_diag,
+        );
+
+        let mut diagnostics = ContextDiagnostics::from_ffi(&ffi_diag);
+
+        if !result.is_ok() || ctx_ptr.is_null() {
+            if diagnostics.message.is_none() {
+                diagnostics.message = Some(if result.is_ok() {
+                    "Context pointer is null".into()
+                } else {
+                    Error::from_result(result).to_string()
+                });
+            }
+            return Err(Error::ContextCreation(diagnostics));
+        }
+
+        Ok((Self::from_created(ctx_ptr, &config), diagnostics))
     }
 
     /// Render a complete frame (chain output + visualizer UI)
@@ -142,6 +656,14 @@ impl Context {
         check_result(result)
     }
 
+    /// Check if the GPU device has been lost
+    ///
+    /// Device loss happens on driver resets, GPU switches, or sleep/wake. Once
+    /// lost, the context cannot recover: drop it and create a new one.
+    pub fn is_device_lost(&self) -> bool {
+        unsafe { vivid_sys::vivid_context_is_device_lost(self.ptr) }
+    }
+
     /// Set visualizer UI visibility
     ///
     /// When false, only the chain output is rendered (useful for fullscreen preview).
@@ -154,6 +676,21 @@ impl Context {
         unsafe { vivid_sys::vivid_context_is_visualizer_visible(self.ptr) }
     }
 
+    /// Draw a placeholder screen while no project is loaded
+    ///
+    /// Without it, an empty context renders black, which looks the same as a
+    /// crashed render. The placeholder is a neutral "no project loaded" screen;
+    /// hosts that overlay their own welcome UI can leave it off and check
+    /// `has_project()` instead.
+    pub fn set_placeholder_enabled(&mut self, enabled: bool) {
+        unsafe { vivid_sys::vivid_context_set_placeholder(self.ptr, enabled) }
+    }
+
+    /// Check if the placeholder screen is enabled
+    pub fn is_placeholder_enabled(&self) -> bool {
+        unsafe { vivid_sys::vivid_context_is_placeholder_enabled(self.ptr) }
+    }
+
     /// Get the name of the currently selected operator in the visualizer
     ///
     /// Returns `None` if no operator is selected.
@@ -168,13 +705,87 @@ impl Context {
 
     /// Select an operator in the visualizer by name
     ///
+    /// Convenience for `set_selection(&[name])`.
     /// The selection will be applied on the next render frame.
     pub fn select_operator(&mut self, name: &str) {
-        if let Ok(c_name) = CString::new(name) {
-            unsafe { vivid_sys::vivid_context_select_operator(self.ptr, c_name.as_ptr()) }
+        self.set_selection(&[name]);
+    }
+
+    /// Replace the visualizer selection with the given operators
+    ///
+    /// Names that don't match an operator are ignored, and an empty slice clears
+    /// the selection. A name containing a NUL byte leaves the selection unchanged
+    /// and is reported by `last_error()`. Clicking a node in the visualizer
+    /// replaces whatever was set here (shift-click extends it), so read the
+    /// current state back with `selection()` rather than caching it on the host
+    /// side.
+    pub fn set_selection(&mut self, names: &[&str]) {
+        let Some(c_names) = names
+            .iter()
+            .map(|name| to_c_string("Operator name", name))
+            .collect::<Option<Vec<CString>>>()
+        else {
+            return;
+        };
+        let ptrs: Vec<*const std::os::raw::c_char> = c_names.iter().map(|n| n.as_ptr()).collect();
+
+        unsafe {
+            vivid_sys::vivid_context_set_selection(self.ptr, ptrs.as_ptr(), ptrs.len() as i32)
         }
     }
 
+    /// Get the names of all selected operators in the visualizer, in selection order
+    pub fn selection(&self) -> Vec<String> {
+        let count = unsafe { vivid_sys::vivid_context_get_selection_count(self.ptr) };
+        (0..count.max(0))
+            .filter_map(|i| {
+                let ptr = unsafe { vivid_sys::vivid_context_get_selection(self.ptr, i) };
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() })
+                }
+            })
+            .collect()
+    }
+
+    /// Enable or disable safe mode
+    ///
+    /// In safe mode, projects loaded afterwards come up with every operator
+    /// bypassed, whatever its class: GPU passes, geometry, audio, video decode,
+    /// and device I/O (cameras, MIDI, NDI/Syphon) all stay idle. Operators are
+    /// still constructed and compiled, so the graph and parameters can be
+    /// inspected and fixed; re-enable operators one at a time with
+    /// `Operator::set_bypassed(false)` to find the one that crashes.
+    ///
+    /// Takes effect on the next `load_project()`/`reload()`.
+    pub fn set_safe_mode(&mut self, enabled: bool) {
+        unsafe { vivid_sys::vivid_context_set_safe_mode(self.ptr, enabled) }
+    }
+
+    /// Check if safe mode is enabled
+    pub fn is_safe_mode(&self) -> bool {
+        unsafe { vivid_sys::vivid_context_is_safe_mode(self.ptr) }
+    }
+
+    /// Set the visualizer's graph camera
+    ///
+    /// `center_x`/`center_y` are in graph space and `zoom` is a scale factor
+    /// (1.0 = default). The view snaps immediately; it isn't animated.
+    pub fn set_graph_view(&mut self, center_x: f32, center_y: f32, zoom: f32) {
+        unsafe { vivid_sys::vivid_context_set_graph_view(self.ptr, center_x, center_y, zoom) }
+    }
+
+    /// Center the visualizer's graph camera on an operator's node
+    ///
+    /// Keeps the current zoom. Returns `false` if no operator has this name.
+    pub fn frame_operator(&mut self, name: &str) -> bool {
+        let Some(c_name) = to_c_string("Operator name", name) else {
+            return false;
+        };
+        unsafe { vivid_sys::vivid_context_focus_operator(self.ptr, c_name.as_ptr()) }
+    }
+
     /// Create a new context with an external wgpu device and queue
     ///
     /// # Arguments
@@ -198,11 +809,7 @@ impl Context {
         let device_ptr = device as *const wgpu::Device as *mut std::ffi::c_void;
         let queue_ptr = queue as *const wgpu::Queue as *mut std::ffi::c_void;
 
-        let ffi_config = vivid_sys::VividContextConfig {
-            width: config.width as i32,
-            height: config.height as i32,
-            enable_validation: config.enable_validation,
-        };
+        let ffi_config = config.to_ffi()?;
 
         let mut ctx_ptr: *mut vivid_sys::VividContext = ptr::null_mut();
 
@@ -221,7 +828,7 @@ impl Context {
             return Err(Error::Internal("Context pointer is null".into()));
         }
 
-        Ok(Self { ptr: ctx_ptr })
+        Ok(Self::from_created(ctx_ptr, &config))
     }
 
     /// Create a context from raw wgpu handles (native pointers)
@@ -236,11 +843,7 @@ impl Context {
         queue: *mut std::ffi::c_void,
         config: ContextConfig,
     ) -> Result<Self> {
-        let ffi_config = vivid_sys::VividContextConfig {
-            width: config.width as i32,
-            height: config.height as i32,
-            enable_validation: config.enable_validation,
-        };
+        let ffi_config = config.to_ffi()?;
 
         let mut ctx_ptr: *mut vivid_sys::VividContext = ptr::null_mut();
 
@@ -257,7 +860,7 @@ impl Context {
             return Err(Error::Internal("Context pointer is null".into()));
         }
 
-        Ok(Self { ptr: ctx_ptr })
+        Ok(Self::from_created(ctx_ptr, &config))
     }
 
     /// Load a project from a directory path
@@ -278,6 +881,11 @@ impl Context {
     /// For embedded use: tells the hot-reload compiler where to find vivid headers
     /// and libraries. Call this before `load_project()` when vivid is embedded as a
     /// submodule and the executable is not in the standard vivid directory structure.
+    ///
+    /// Only compiles started afterwards use the new root; `reload()` an already
+    /// loaded project to rebuild it. Asset lookup is separate and must be set up
+    /// with `configure_asset_paths()` before the context is created. Returns
+    /// `Error::InvalidArgument` if the path contains a NUL byte.
     pub fn set_root_dir<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
         let path_str = path.as_ref().to_string_lossy();
         let c_path = CString::new(path_str.as_ref())
@@ -288,12 +896,69 @@ impl Context {
         check_result(result)
     }
 
+    /// Enable or disable re-importing assets when they change on disk
+    ///
+    /// Watches only files referenced by `FilePath` parameters (textures, videos,
+    /// audio, ...), not the project source. When one changes, the operators that
+    /// reference it re-import it in place; the chain is not recompiled and other
+    /// state is untouched. Changes are debounced by vivid-core so a save that
+    /// writes the file in several steps triggers a single re-import.
+    pub fn set_asset_watch_enabled(&mut self, enabled: bool) {
+        unsafe { vivid_sys::vivid_context_set_asset_watch(self.ptr, enabled) }
+    }
+
+    /// Check if asset watching is enabled
+    pub fn is_asset_watch_enabled(&self) -> bool {
+        unsafe { vivid_sys::vivid_context_is_asset_watch_enabled(self.ptr) }
+    }
+
     /// Reload the current project
     pub fn reload(&mut self) -> Result<()> {
         let result = unsafe { vivid_sys::vivid_context_reload(self.ptr) };
         check_result(result)
     }
 
+    /// Save current parameter values back into the project
+    ///
+    /// vivid-core rewrites the parameter values in `chain.cpp` so edits made with
+    /// `set_param()` survive a reload; afterwards `Operator::saved_param()` reports
+    /// the saved values. Returns `Error::NoProject` if no project is loaded.
+    pub fn save_project(&self) -> Result<()> {
+        self.save_project_to(None)
+    }
+
+    /// Save the project with current parameter values to another directory
+    ///
+    /// Writes a copy of the project to `path`, leaving the original untouched
+    /// and still loaded. Returns `Error::NoProject` if no project is loaded.
+    pub fn save_project_as<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.save_project_to(Some(path.as_ref()))
+    }
+
+    fn save_project_to(&self, path: Option<&Path>) -> Result<()> {
+        if self.project_path().is_none() {
+            return Err(Error::NoProject);
+        }
+
+        let c_path = path
+            .map(|p| {
+                CString::new(p.to_string_lossy().as_ref())
+                    .map_err(|_| Error::InvalidArgument("Invalid path".into()))
+            })
+            .transpose()?;
+
+        let result = unsafe {
+            vivid_sys::vivid_context_save_project(
+                self.ptr,
+                c_path.as_ref().map_or(ptr::null(), |p| p.as_ptr()),
+            )
+        };
+        match check_result(result) {
+            Err(Error::NoChain) => Err(Error::NoProject),
+            other => other,
+        }
+    }
+
     /// Unload the current project
     pub fn unload_project(&mut self) -> Result<()> {
         let result = unsafe { vivid_sys::vivid_context_unload_project(self.ptr) };
@@ -303,30 +968,77 @@ impl Context {
     /// Get the compilation status
     pub fn compile_status(&self) -> CompileStatus {
         let status = unsafe { vivid_sys::vivid_context_get_compile_status(self.ptr) };
+        CompileStatus::from_ffi(&status)
+    }
 
-        let message = if status.message.is_null() {
-            None
-        } else {
-            Some(unsafe { CStr::from_ptr(status.message).to_string_lossy().into_owned() })
-        };
+    /// Set a callback invoked whenever the core recompiles
+    ///
+    /// Fires for recompiles the host didn't initiate too (e.g. auto-reload after a
+    /// file change), so it's more reliable than polling `compile_status()`. The
+    /// callback runs on the thread driving the context; panics inside it are caught
+    /// and discarded. Replaces any previously set callback.
+    pub fn set_compile_callback(&mut self, callback: impl FnMut(CompileStatus) + Send + 'static) {
+        let mut boxed: Box<CompileCallback> = Box::new(Box::new(callback));
+        let user_data = &mut *boxed as *mut CompileCallback as *mut std::ffi::c_void;
+
+        unsafe {
+            vivid_sys::vivid_context_set_compile_callback(
+                self.ptr,
+                Some(compile_callback_trampoline),
+                user_data,
+            );
+        }
 
-        let error_line = if status.error_line > 0 {
-            Some(status.error_line as u32)
-        } else {
-            None
-        };
+        // Drop the old closure only after the core points at the new one
+        self.compile_callback = Some(boxed);
+    }
 
-        let error_column = if status.error_column > 0 {
-            Some(status.error_column as u32)
-        } else {
-            None
-        };
+    /// Remove the compile callback
+    pub fn clear_compile_callback(&mut self) {
+        if self.compile_callback.take().is_some() {
+            unsafe {
+                vivid_sys::vivid_context_set_compile_callback(self.ptr, None, ptr::null_mut());
+            }
+        }
+    }
 
-        CompileStatus {
-            success: status.success,
-            message,
-            error_line,
-            error_column,
+    /// Set a callback invoked when an operator fails at runtime
+    ///
+    /// vivid-core runs each operator in isolation: when one fails (shader trap,
+    /// bad input), its output goes black and the rest of the chain keeps
+    /// rendering instead of the whole frame failing. The callback receives the
+    /// operator's name and the error message. It fires when an operator starts
+    /// failing, not on every frame it stays failed; poll
+    /// `Operator::runtime_error()` for the current state. Panics inside it are
+    /// caught and discarded. Replaces any previously set callback.
+    pub fn set_operator_error_callback(
+        &mut self,
+        callback: impl FnMut(&str, &str) + Send + 'static,
+    ) {
+        let mut boxed: Box<OperatorErrorCallback> = Box::new(Box::new(callback));
+        let user_data = &mut *boxed as *mut OperatorErrorCallback as *mut std::ffi::c_void;
+
+        unsafe {
+            vivid_sys::vivid_context_set_operator_error_callback(
+                self.ptr,
+                Some(operator_error_trampoline),
+                user_data,
+            );
+        }
+
+        self.operator_error_callback = Some(boxed);
+    }
+
+    /// Remove the operator error callback
+    pub fn clear_operator_error_callback(&mut self) {
+        if self.operator_error_callback.take().is_some() {
+            unsafe {
+                vivid_sys::vivid_context_set_operator_error_callback(
+                    self.ptr,
+                    None,
+                    ptr::null_mut(),
+                );
+            }
         }
     }
 
@@ -335,6 +1047,31 @@ impl Context {
         unsafe { vivid_sys::vivid_context_has_project(self.ptr) }
     }
 
+    /// Get how completely the current project loaded
+    ///
+    /// Unlike `has_project()`, this distinguishes "loaded but one node is broken"
+    /// from a clean load.
+    pub fn load_state(&self) -> LoadState {
+        match unsafe { vivid_sys::vivid_context_get_load_state(self.ptr) } {
+            vivid_sys::VividLoadState::Empty => LoadState::Empty,
+            vivid_sys::VividLoadState::FullyLoaded => LoadState::FullyLoaded,
+            vivid_sys::VividLoadState::PartiallyLoaded => {
+                let count = unsafe { vivid_sys::vivid_context_get_failed_operator_count(self.ptr) };
+                let failed_ops = (0..count.max(0))
+                    .filter_map(|i| {
+                        let ptr = unsafe { vivid_sys::vivid_context_get_failed_operator(self.ptr, i) };
+                        if ptr.is_null() {
+                            None
+                        } else {
+                            Some(unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() })
+                        }
+                    })
+                    .collect();
+                LoadState::PartiallyLoaded { failed_ops }
+            }
+        }
+    }
+
     /// Get the loaded project path
     pub fn project_path(&self) -> Option<String> {
         let ptr = unsafe { vivid_sys::vivid_context_get_project_path(self.ptr) };
@@ -345,6 +1082,72 @@ impl Context {
         }
     }
 
+    /// Get the compilation cache directory of the loaded project
+    ///
+    /// Deleting this directory forces a clean rebuild on the next load/reload.
+    /// Returns `None` if no project is loaded.
+    pub fn project_build_dir(&self) -> Option<PathBuf> {
+        let ptr = unsafe { vivid_sys::vivid_context_get_build_dir(self.ptr) };
+        if ptr.is_null() {
+            None
+        } else {
+            let path = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+            Some(PathBuf::from(path))
+        }
+    }
+
+    /// Get the resolution declared in the loaded project's manifest
+    ///
+    /// Returns `None` if no project is loaded or the project doesn't declare one.
+    /// Loading a project never changes the render resolution by itself; call
+    /// `apply_project_resolution()` to adopt it.
+    pub fn project_resolution(&self) -> Option<(u32, u32)> {
+        let mut width = 0;
+        let mut height = 0;
+        let declared = unsafe {
+            vivid_sys::vivid_context_get_project_resolution(self.ptr, &mut width, &mut height)
+        };
+
+        if declared && width > 0 && height > 0 {
+            Some((width as u32, height as u32))
+        } else {
+            None
+        }
+    }
+
+    /// Switch the render resolution to the one declared by the loaded project
+    ///
+    /// Returns `Ok(true)` if the resolution was changed, or `Ok(false)` if the
+    /// project doesn't declare a resolution.
+    pub fn apply_project_resolution(&mut self) -> Result<bool> {
+        match self.project_resolution() {
+            Some((width, height)) => {
+                self.set_resolution(width, height)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Get the GPU features the loaded chain requires
+    ///
+    /// Computed from the loaded operators (e.g. float-texture filtering, compute
+    /// shaders). Empty when no project is loaded or the chain only needs baseline
+    /// features.
+    pub fn chain_required_features(&self) -> Vec<String> {
+        let count = unsafe { vivid_sys::vivid_context_get_required_feature_count(self.ptr) };
+        (0..count.max(0))
+            .filter_map(|i| {
+                let ptr = unsafe { vivid_sys::vivid_context_get_required_feature(self.ptr, i) };
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() })
+                }
+            })
+            .collect()
+    }
+
     /// Process a single frame
     ///
     /// # Arguments
@@ -370,6 +1173,83 @@ impl Context {
         unsafe { vivid_sys::vivid_context_reset_time(self.ptr) }
     }
 
+    /// Jump the clock to `seconds` without processing a frame
+    ///
+    /// Only the time accumulator changes: `frame()` keeps counting from where it
+    /// was (use `reset_time()` first to restart it), and the next
+    /// `process_frame(dt)` advances from here. Returns `Error::InvalidArgument`
+    /// for negative or non-finite times.
+    pub fn set_time(&mut self, seconds: f64) -> Result<()> {
+        if !seconds.is_finite() || seconds < 0.0 {
+            return Err(Error::InvalidArgument(format!("Invalid time: {}", seconds)));
+        }
+
+        let result = unsafe { vivid_sys::vivid_context_set_time(self.ptr, seconds) };
+        check_result(result)
+    }
+
+    /// Get counters accumulated since the context was created
+    ///
+    /// Unlike `frame()` and `time()`, these are never reset by `reset_time()` or
+    /// project loads, so long-running installations can report total output and
+    /// detect a hang by watching `total_frames` stall.
+    pub fn session_stats(&self) -> SessionStats {
+        let mut stats = vivid_sys::VividSessionStats::default();
+        unsafe { vivid_sys::vivid_context_get_session_stats(self.ptr, &mut stats) };
+        SessionStats {
+            total_frames: stats.total_frames,
+            total_render_seconds: stats.total_render_seconds,
+            uptime_seconds: stats.uptime_seconds,
+        }
+    }
+
+    /// Get recent frame times in milliseconds, oldest first
+    ///
+    /// vivid-core keeps a ring of how long each `process_frame()` took, longer
+    /// than the FPS averages a host typically tracks, so periodic hitches show
+    /// up. Empty until a frame has been processed.
+    pub fn recent_frame_times(&self) -> Vec<f32> {
+        let available =
+            unsafe { vivid_sys::vivid_context_get_frame_times(self.ptr, ptr::null_mut(), 0) };
+        if available <= 0 {
+            return Vec::new();
+        }
+
+        let mut times = vec![0.0f32; available as usize];
+        let written = unsafe {
+            vivid_sys::vivid_context_get_frame_times(self.ptr, times.as_mut_ptr(), available)
+        };
+        times.truncate(written.clamp(0, available) as usize);
+        times
+    }
+
+    /// Bin `recent_frame_times()` into a histogram with `buckets` equal bins
+    ///
+    /// The bins span 0 ms to the slowest recent frame, so bucket `i` counts
+    /// frames in `[i * max / buckets, (i + 1) * max / buckets)` (the last bucket
+    /// includes `max`). A steady chain piles up in one bucket; hitches show as a
+    /// tail toward the end. Returns all zeros if no frames were recorded, and an
+    /// empty vec if `buckets` is 0.
+    pub fn frame_time_histogram(&self, buckets: usize) -> Vec<u32> {
+        let mut histogram = vec![0u32; buckets];
+        if buckets == 0 {
+            return histogram;
+        }
+
+        let times = self.recent_frame_times();
+        let max = times.iter().copied().fold(0.0f32, f32::max);
+        if max <= 0.0 {
+            histogram[0] = times.len() as u32;
+            return histogram;
+        }
+
+        for t in times {
+            let bucket = ((t / max) * buckets as f32) as usize;
+            histogram[bucket.min(buckets - 1)] += 1;
+        }
+        histogram
+    }
+
     /// Set render resolution
     pub fn set_resolution(&mut self, width: u32, height: u32) -> Result<()> {
         let result = unsafe {
@@ -388,6 +1268,24 @@ impl Context {
         unsafe { vivid_sys::vivid_context_get_height(self.ptr) as u32 }
     }
 
+    /// Temporarily render at a fraction of the resolution, e.g. while dragging a slider
+    ///
+    /// Operators render into a `scale`-sized region of their existing textures and
+    /// the result is upscaled to the full output with bilinear filtering, so no
+    /// textures are reallocated when the scale changes. `width()`/`height()` keep
+    /// reporting the full resolution. Set it back to `1.0` to commit to full
+    /// quality. This is user-driven and independent of any automatic scaling in
+    /// vivid-core. `scale` is clamped to `0.1..=1.0`; non-finite values reset it.
+    pub fn set_preview_scale(&mut self, scale: f32) {
+        let scale = if scale.is_finite() { scale.clamp(0.1, 1.0) } else { 1.0 };
+        unsafe { vivid_sys::vivid_context_set_preview_scale(self.ptr, scale) }
+    }
+
+    /// Get the current preview scale (1.0 = full resolution)
+    pub fn preview_scale(&self) -> f32 {
+        unsafe { vivid_sys::vivid_context_get_preview_scale(self.ptr) }
+    }
+
     /// Set mouse position
     pub fn set_mouse_position(&mut self, x: f32, y: f32) {
         unsafe { vivid_sys::vivid_context_set_mouse_position(self.ptr, x, y) }
@@ -408,6 +1306,40 @@ impl Context {
         unsafe { vivid_sys::vivid_context_add_scroll(self.ptr, dx, dy) }
     }
 
+    /// Apply a batch of input events in one call
+    ///
+    /// Equivalent to calling `set_mouse_position`, `set_mouse_button`, `set_key`,
+    /// and `add_scroll` for each recorded event, but crosses the FFI boundary once
+    /// and guarantees the whole batch lands before the next processed frame.
+    pub fn apply_input_frame(&mut self, frame: &InputFrame) {
+        let to_events = |events: &[(u32, bool)]| -> Vec<vivid_sys::VividInputEvent> {
+            events
+                .iter()
+                .map(|&(code, pressed)| vivid_sys::VividInputEvent {
+                    code: code as i32,
+                    pressed,
+                })
+                .collect()
+        };
+        let buttons = to_events(&frame.mouse_buttons);
+        let keys = to_events(&frame.keys);
+        let (mouse_x, mouse_y) = frame.mouse_position.unwrap_or((0.0, 0.0));
+
+        let ffi_frame = vivid_sys::VividInputFrame {
+            has_mouse_position: frame.mouse_position.is_some(),
+            mouse_x,
+            mouse_y,
+            scroll_dx: frame.scroll.0,
+            scroll_dy: frame.scroll.1,
+            mouse_buttons: buttons.as_ptr(),
+            mouse_button_count: buttons.len() as i32,
+            keys: keys.as_ptr(),
+            key_count: keys.len() as i32,
+        };
+
+        unsafe { vivid_sys::vivid_context_apply_input_frame(self.ptr, &ffi_frame) }
+    }
+
     /// Get the chain
     ///
     /// Returns `None` if no project is loaded.
@@ -420,6 +1352,51 @@ impl Context {
         }
     }
 
+    /// Get the color space of the chain output
+    ///
+    /// Needed to encode read-back pixels correctly: linear output must be
+    /// gamma-encoded before saving as PNG. Chains that don't report a color space
+    /// return `Unknown`, which should be treated as sRGB.
+    pub fn output_color_space(&self) -> ColorSpace {
+        unsafe { vivid_sys::vivid_context_get_output_color_space(self.ptr) }.into()
+    }
+
+    /// Get how the chain output encodes alpha
+    ///
+    /// `TextureInfo::has_alpha` says whether alpha exists; this says how to
+    /// interpret it. Readback, PNG export, and NDI/Syphon output need it to avoid
+    /// dark halos from mishandled premultiplication.
+    pub fn output_alpha_mode(&self) -> AlphaMode {
+        unsafe { vivid_sys::vivid_context_get_alpha_mode(self.ptr) }.into()
+    }
+
+    /// Override how the chain output encodes alpha
+    pub fn set_output_alpha_mode(&mut self, mode: AlphaMode) {
+        unsafe { vivid_sys::vivid_context_set_alpha_mode(self.ptr, mode.into()) }
+    }
+
+    /// Check if the chain output has produced a valid frame since the last load
+    ///
+    /// After `load_project()` the output may be missing for a frame or two while
+    /// resources warm up; snapshots or readback taken before then come back blank.
+    pub fn is_output_ready(&self) -> bool {
+        unsafe { vivid_sys::vivid_context_is_output_ready(self.ptr) }
+    }
+
+    /// Process frames until the output is ready (headless use)
+    ///
+    /// Steps the chain at 60fps for up to `max_frames` frames. Returns `true` once
+    /// `is_output_ready()` reports a valid frame, or `false` if it never did.
+    pub fn render_until_ready(&mut self, max_frames: u32) -> Result<bool> {
+        for _ in 0..max_frames {
+            if self.is_output_ready() {
+                return Ok(true);
+            }
+            self.process_frame(1.0 / 60.0)?;
+        }
+        Ok(self.is_output_ready())
+    }
+
     /// Get the output texture view
     ///
     /// Returns the raw WebGPU texture view pointer from the chain's output.
@@ -447,6 +1424,9 @@ impl Context {
     }
 
     /// Capture the current output to a PNG file
+    ///
+    /// The PNG is always sRGB: vivid-core gamma-encodes linear output (see
+    /// `output_color_space()`) before writing.
     pub fn capture_snapshot<P: AsRef<Path>>(&self, path: P) -> Result<()> {
         let path_str = path.as_ref().to_string_lossy();
         let c_path = CString::new(path_str.as_ref())
@@ -456,20 +1436,594 @@ impl Context {
         check_result(result)
     }
 
+    /// Capture the current output to a file in the given format
+    ///
+    /// vivid-core only encodes PNG, so other formats are decoded from the PNG
+    /// snapshot and re-encoded here. JPEG has no alpha channel, so the image is
+    /// flattened to RGB first (alpha is dropped, not composited).
+    pub fn capture_snapshot_as<P: AsRef<Path>>(&self, path: P, format: ImageFormat) -> Result<()> {
+        let path = path.as_ref();
+        if format == ImageFormat::Png {
+            return self.capture_snapshot(path);
+        }
+
+        let png = self.capture_snapshot_png(None)?;
+        let image = image::load_from_memory_with_format(&png, image::ImageFormat::Png)
+            .map_err(|e| Error::Internal(format!("Failed to decode snapshot: {}", e)))?;
+
+        let file = std::fs::File::create(path)
+            .map_err(|e| Error::InvalidArgument(format!("Cannot create {:?}: {}", path, e)))?;
+        let mut writer = std::io::BufWriter::new(file);
+
+        let encoded = match format {
+            ImageFormat::Png => unreachable!("handled above"),
+            ImageFormat::Jpeg { quality } => {
+                let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(
+                    &mut writer,
+                    quality.clamp(1, 100),
+                );
+                image.to_rgb8().write_with_encoder(encoder)
+            }
+            ImageFormat::WebP => {
+                let encoder = image::codecs::webp::WebPEncoder::new_lossless(&mut writer);
+                image.to_rgba8().write_with_encoder(encoder)
+            }
+        };
+        encoded.map_err(|e| Error::Internal(format!("Failed to encode {:?}: {}", path, e)))?;
+        std::io::Write::flush(&mut writer)
+            .map_err(|e| Error::Internal(format!("Failed to write {:?}: {}", path, e)))
+    }
+
+    /// Render and capture a numbered PNG sequence into `dir` (headless export)
+    ///
+    /// Resets the clock, then for each frame steps the chain by `1 / fps` and writes
+    /// `frame_00000.png`, `frame_00001.png`, ... After each frame is written,
+    /// `progress(written, frame_count)` is called; returning `false` cancels.
+    ///
+    /// Cancellation only happens between frames, so the clock and frame counter
+    /// always match the frames written (no frame is processed without being
+    /// captured). Returns the number of frames written.
+    pub fn capture_sequence<P, F>(
+        &mut self,
+        dir: P,
+        frame_count: u32,
+        fps: f64,
+        mut progress: F,
+    ) -> Result<u32>
+    where
+        P: AsRef<Path>,
+        F: FnMut(u32, u32) -> bool,
+    {
+        if !fps.is_finite() || fps <= 0.0 {
+            return Err(Error::InvalidArgument("fps must be positive".into()));
+        }
+
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)
+            .map_err(|e| Error::InvalidArgument(format!("Cannot create {:?}: {}", dir, e)))?;
+
+        self.reset_time();
+        let dt = 1.0 / fps;
+
+        for frame in 0..frame_count {
+            self.process_frame(dt)?;
+            self.capture_snapshot(dir.join(format!("frame_{:05}.png", frame)))?;
+
+            let written = frame + 1;
+            if !progress(written, frame_count) {
+                return Ok(written);
+            }
+        }
+
+        Ok(frame_count)
+    }
+
+    /// Process `frame_count` frames at a fixed `dt`, calling `on_frame` after each
+    ///
+    /// The common offline-export loop: every frame advances the clock by exactly
+    /// `dt`, independent of wall-clock time, so output is reproducible. `on_frame`
+    /// gets the context (e.g. to `capture_snapshot()`) and the frame's index in
+    /// the sequence, starting at 0. The clock isn't reset first; call
+    /// `reset_time()` or `set_time()` beforehand to choose the start.
+    ///
+    /// An error from `process_frame()` or `on_frame` stops the loop and is
+    /// returned.
+    pub fn render_sequence<F>(&mut self, frame_count: u32, dt: f64, mut on_frame: F) -> Result<()>
+    where
+        F: FnMut(&Self, u64) -> Result<()>,
+    {
+        if !dt.is_finite() || dt <= 0.0 {
+            return Err(Error::InvalidArgument("dt must be positive".into()));
+        }
+
+        for frame in 0..u64::from(frame_count) {
+            self.process_frame(dt)?;
+            on_frame(self, frame)?;
+        }
+
+        Ok(())
+    }
+
+    /// Render a looping animation to an animated GIF or PNG
+    ///
+    /// Resets the clock, then renders `frame_count` frames at a fixed `dt`, just
+    /// like `capture_sequence()`, so the same chain always produces the same
+    /// file. For a seamless loop, make `frame_count * dt` match the chain's cycle
+    /// length. Each frame is shown for `dt` (rounded to 10 ms steps for GIF) and
+    /// the file loops forever. After each frame, `progress(rendered, frame_count)`
+    /// is called; returning `false` cancels.
+    ///
+    /// Frames are encoded as they render, so memory use doesn't grow with length.
+    /// A cancelled or failed capture deletes the partial file; cancelling still
+    /// returns `Ok(())`.
+    pub fn capture_loop<F>(
+        &mut self,
+        path: &Path,
+        frame_count: u32,
+        dt: f64,
+        format: LoopFormat,
+        mut progress: F,
+    ) -> Result<()>
+    where
+        F: FnMut(u32, u32) -> bool,
+    {
+        if !dt.is_finite() || dt <= 0.0 {
+            return Err(Error::InvalidArgument("dt must be positive".into()));
+        }
+        if frame_count == 0 {
+            return Err(Error::InvalidArgument("frame_count must be positive".into()));
+        }
+
+        let file = std::fs::File::create(path)
+            .map_err(|e| Error::InvalidArgument(format!("Cannot create {:?}: {}", path, e)))?;
+        let writer = std::io::BufWriter::new(file);
+
+        let result = self.encode_loop(writer, frame_count, dt, format, &mut progress);
+        if !matches!(result, Ok(true)) {
+            let _ = std::fs::remove_file(path);
+        }
+        result.map(|_| ())
+    }
+
+    /// Render and encode the frames for `capture_loop()`, returning `false` if
+    /// `progress` cancelled
+    fn encode_loop<W, F>(
+        &mut self,
+        writer: W,
+        frame_count: u32,
+        dt: f64,
+        format: LoopFormat,
+        progress: &mut F,
+    ) -> Result<bool>
+    where
+        W: std::io::Write,
+        F: FnMut(u32, u32) -> bool,
+    {
+        self.reset_time();
+
+        let mut writer = Some(writer);
+        let mut encoder = None;
+        let mut pixels = Vec::new();
+
+        for frame in 0..frame_count {
+            self.process_frame(dt)?;
+            let (width, height) = self.read_output_pixels_into(&mut pixels)?;
+
+            // The size is only known once the first frame has rendered
+            let encoder = match &mut encoder {
+                Some(encoder) => encoder,
+                None => {
+                    let writer = writer.take().expect("writer is only taken once");
+                    encoder.insert(LoopEncoder::new(
+                        writer,
+                        width,
+                        height,
+                        frame_count,
+                        dt,
+                        format,
+                    )?)
+                }
+            };
+            encoder.write_frame(width, height, &pixels)?;
+
+            if !progress(frame + 1, frame_count) {
+                return Ok(false);
+            }
+        }
+
+        if let Some(encoder) = encoder {
+            encoder.finish()?;
+        }
+        Ok(true)
+    }
+
+    /// Capture the current output at full resolution as PNG-encoded bytes
+    ///
+    /// The in-memory counterpart of `capture_snapshot()`, e.g. for streaming
+    /// thumbnails over a socket. The bytes are a complete PNG file: 8-bit RGBA,
+    /// sRGB-encoded, straight (non-premultiplied) alpha. Same as
+    /// `capture_snapshot_png(None)`.
+    pub fn capture_snapshot_bytes(&self) -> Result<Vec<u8>> {
+        self.capture_snapshot_png(None)
+    }
+
+    /// Capture the current output as PNG-encoded bytes
+    ///
+    /// Avoids a temp file when the pixels are only needed in memory (e.g. a preview
+    /// thumbnail). With `max_dimension`, the image is downscaled so neither side
+    /// exceeds it, keeping the encoded size small. Like `capture_snapshot()`, the
+    /// result is sRGB-encoded regardless of `output_color_space()`.
+    pub fn capture_snapshot_png(&self, max_dimension: Option<u32>) -> Result<Vec<u8>> {
+        let mut data: *mut u8 = ptr::null_mut();
+        let mut size: usize = 0;
+
+        let result = unsafe {
+            vivid_sys::vivid_context_capture_snapshot_png(
+                self.ptr,
+                max_dimension.map_or(0, |d| d.min(i32::MAX as u32) as i32),
+                &mut data,
+                &mut size,
+            )
+        };
+        check_result(result)?;
+
+        if data.is_null() {
+            return Err(Error::Internal("Snapshot buffer is null".into()));
+        }
+
+        let bytes = unsafe { std::slice::from_raw_parts(data, size) }.to_vec();
+        unsafe { vivid_sys::vivid_free_buffer(data) };
+
+        Ok(bytes)
+    }
+
+    /// Read back the current output as raw RGBA8 pixels
+    ///
+    /// Returns `(width, height, pixels)`, for feeding frames to code that wants
+    /// uncompressed bytes (e.g. an ML pipeline) without a PNG round trip.
+    ///
+    /// Rows are tightly packed: the stride is always `width * 4` bytes and
+    /// `pixels.len() == width * height * 4`. vivid-core strips the 256-byte row
+    /// alignment the GPU copy needs, so pixel `(x, y)` starts at
+    /// `(y * width + x) * 4`, top row first. Like `capture_snapshot()`, values
+    /// are sRGB-encoded with straight (non-premultiplied) alpha regardless of
+    /// `output_color_space()`.
+    ///
+    /// This waits for the GPU, so calling it every frame costs a pipeline stall.
+    /// Use `read_output_pixels_into()` to reuse one buffer across frames.
+    pub fn read_output_pixels(&self) -> Result<(u32, u32, Vec<u8>)> {
+        let mut pixels = Vec::new();
+        let (width, height) = self.read_output_pixels_into(&mut pixels)?;
+        Ok((width, height, pixels))
+    }
+
+    /// Read back the current output into an existing buffer
+    ///
+    /// Like `read_output_pixels()` (same tightly packed RGBA8 layout, stride
+    /// `width * 4`), but reuses `buf` so streaming every frame doesn't allocate.
+    /// `buf` is resized to exactly `width * height * 4` bytes, which only
+    /// reallocates when the output grows past its capacity. Returns
+    /// `(width, height)`, and `Error::InvalidArgument` if `buf` can't grow that
+    /// far.
+    pub fn read_output_pixels_into(&self, buf: &mut Vec<u8>) -> Result<(u32, u32)> {
+        let mut width: u32 = 0;
+        let mut height: u32 = 0;
+
+        // Size query only: nothing is copied with a null buffer
+        let result = unsafe {
+            vivid_sys::vivid_context_read_output_pixels_into(
+                self.ptr,
+                ptr::null_mut(),
+                0,
+                &mut width,
+                &mut height,
+            )
+        };
+        check_result(result)?;
+
+        let needed = (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|n| n.checked_mul(4))
+            .ok_or_else(|| {
+                Error::Internal(format!("Output size overflows: {}x{}", width, height))
+            })?;
+        if let Some(extra) = needed.checked_sub(buf.len()) {
+            buf.try_reserve(extra).map_err(|e| {
+                Error::InvalidArgument(format!(
+                    "Cannot grow pixel buffer to {} bytes: {}",
+                    needed, e
+                ))
+            })?;
+        }
+        buf.resize(needed, 0);
+
+        let result = unsafe {
+            vivid_sys::vivid_context_read_output_pixels_into(
+                self.ptr,
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut width,
+                &mut height,
+            )
+        };
+        check_result(result)?;
+
+        // The output can only have shrunk since the query, or the copy would have failed
+        buf.truncate(width as usize * height as usize * 4);
+        Ok((width, height))
+    }
+
+    /// Get a fast hash of the current output texture's pixels
+    ///
+    /// Cheap enough for CI smoke tests: render a fixed number of frames and
+    /// compare against a golden value instead of storing reference images.
+    /// Returns `None` if there is no output texture.
+    ///
+    /// The hash covers raw GPU results, so it is only stable across runs on the
+    /// same GPU and driver. Drive frames with a fixed `dt` via `process_frame()`
+    /// after `reset_time()`, and make sure any randomness in the chain is
+    /// seeded, otherwise the value changes between runs.
+    pub fn output_hash(&self) -> Option<u64> {
+        let mut hash = 0u64;
+        let has_output = unsafe { vivid_sys::vivid_context_get_output_hash(self.ptr, &mut hash) };
+        has_output.then_some(hash)
+    }
+
+    /// Export a project as a standalone runnable app
+    ///
+    /// Like the free `export_standalone()`, but bundles the loaded project when
+    /// `options.project_path` is unset (`Error::NoProject` if nothing is loaded).
+    /// The export doesn't use the context, so hosts that share it behind a lock
+    /// should call the free function instead and keep rendering meanwhile.
+    pub fn export_standalone(&self, mut options: BundleOptions) -> Result<PathBuf> {
+        if options.project_path.is_none() {
+            options.project_path = Some(self.project_path().ok_or(Error::NoProject)?.into());
+        }
+        export_standalone(options)
+    }
+
     /// Get the raw context pointer (for advanced usage)
     pub fn as_raw(&self) -> *mut vivid_sys::VividContext {
         self.ptr
     }
 }
 
+/// Streaming encoder behind `Context::capture_loop()`
+enum LoopEncoder<W: std::io::Write> {
+    Gif {
+        encoder: gif::Encoder<W>,
+        width: u32,
+        height: u32,
+        palette_size: usize,
+        delay_cs: u16,
+    },
+    Apng {
+        writer: png::Writer<W>,
+        width: u32,
+        height: u32,
+    },
+}
+
+impl<W: std::io::Write> LoopEncoder<W> {
+    fn new(
+        writer: W,
+        width: u32,
+        height: u32,
+        frame_count: u32,
+        dt: f64,
+        format: LoopFormat,
+    ) -> Result<Self> {
+        match format {
+            LoopFormat::Gif { palette_size } => {
+                let (Ok(w), Ok(h)) = (u16::try_from(width), u16::try_from(height)) else {
+                    return Err(Error::InvalidArgument(format!(
+                        "{}x{} is too large for GIF",
+                        width, height
+                    )));
+                };
+                let mut encoder = gif::Encoder::new(writer, w, h, &[]).map_err(encode_error)?;
+                encoder.set_repeat(gif::Repeat::Infinite).map_err(encode_error)?;
+                Ok(LoopEncoder::Gif {
+                    encoder,
+                    width,
+                    height,
+                    palette_size: usize::from(palette_size.clamp(2, 256)),
+                    delay_cs: (dt * 100.0).round().clamp(1.0, u16::MAX as f64) as u16,
+                })
+            }
+            LoopFormat::Apng => {
+                let mut encoder = png::Encoder::new(writer, width, height);
+                encoder.set_color(png::ColorType::Rgba);
+                encoder.set_depth(png::BitDepth::Eight);
+                encoder.set_animated(frame_count, 0).map_err(encode_error)?;
+                let delay_ms = (dt * 1000.0).round().clamp(1.0, u16::MAX as f64) as u16;
+                encoder.set_frame_delay(delay_ms, 1000).map_err(encode_error)?;
+                let writer = encoder.write_header().map_err(encode_error)?;
+                Ok(LoopEncoder::Apng {
+                    writer,
+                    width,
+                    height,
+                })
+            }
+        }
+    }
+
+    /// Encode one tightly packed RGBA8 frame
+    fn write_frame(&mut self, frame_width: u32, frame_height: u32, rgba: &[u8]) -> Result<()> {
+        let (width, height) = match self {
+            LoopEncoder::Gif { width, height, .. } | LoopEncoder::Apng { width, height, .. } => {
+                (*width, *height)
+            }
+        };
+        if (frame_width, frame_height) != (width, height) {
+            return Err(Error::InvalidArgument(format!(
+                "Output resized from {}x{} to {}x{} during capture",
+                width, height, frame_width, frame_height
+            )));
+        }
+
+        match self {
+            LoopEncoder::Gif {
+                encoder,
+                palette_size,
+                delay_cs,
+                ..
+            } => {
+                let quant = color_quant::NeuQuant::new(10, *palette_size, rgba);
+                let indices: Vec<u8> = rgba
+                    .chunks_exact(4)
+                    .map(|pixel| quant.index_of(pixel) as u8)
+                    .collect();
+                let frame = gif::Frame {
+                    width: width as u16,
+                    height: height as u16,
+                    delay: *delay_cs,
+                    palette: Some(quant.color_map_rgb()),
+                    buffer: indices.into(),
+                    ..Default::default()
+                };
+                encoder.write_frame(&frame).map_err(encode_error)
+            }
+            LoopEncoder::Apng { writer, .. } => writer.write_image_data(rgba).map_err(encode_error),
+        }
+    }
+
+    fn finish(self) -> Result<()> {
+        match self {
+            LoopEncoder::Gif { encoder, .. } => {
+                let mut writer = encoder.into_inner().map_err(encode_error)?;
+                writer.flush().map_err(encode_error)
+            }
+            LoopEncoder::Apng { writer, .. } => writer.finish().map_err(encode_error),
+        }
+    }
+}
+
+fn encode_error(e: impl std::fmt::Display) -> Error {
+    Error::Internal(format!("Failed to encode loop: {}", e))
+}
+
 impl Drop for Context {
     fn drop(&mut self) {
         if !self.ptr.is_null() {
+            // Unregister before destroying so the core can't call into a freed closure
+            self.clear_compile_callback();
+            self.clear_operator_error_callback();
             unsafe { vivid_sys::vivid_context_destroy(self.ptr) };
         }
     }
 }
 
+/// Export a project as a standalone runnable app
+///
+/// Bundles in process, so it works without the `vivid` CLI binary being built.
+/// Needs no context and can run on any thread. `options.project_path` is
+/// required (`Error::InvalidArgument` otherwise). Returns the path of the
+/// created bundle.
+pub fn export_standalone(options: BundleOptions) -> Result<PathBuf> {
+    let to_cstring = |s: &str| {
+        CString::new(s).map_err(|_| Error::InvalidArgument("Invalid export option".into()))
+    };
+    let project_path = options.project_path
+        .ok_or_else(|| Error::InvalidArgument("No project path to export".into()))?;
+    let project_path = to_cstring(&project_path.to_string_lossy())?;
+    let output_dir = options.output_dir
+        .map(|p| to_cstring(&p.to_string_lossy())).transpose()?;
+    let app_name = options.app_name.as_deref().map(to_cstring).transpose()?;
+    let platform = options.platform.as_deref().map(to_cstring).transpose()?;
+
+    let as_ptr = |s: &Option<CString>| s.as_ref().map_or(ptr::null(), |s| s.as_ptr());
+    let ffi_options = vivid_sys::VividExportOptions {
+        project_path: project_path.as_ptr(),
+        output_dir: as_ptr(&output_dir),
+        app_name: as_ptr(&app_name),
+        platform: as_ptr(&platform),
+    };
+
+    let mut out_path: *const std::os::raw::c_char = ptr::null();
+    let result = unsafe { vivid_sys::vivid_export_standalone(&ffi_options, &mut out_path) };
+    check_result(result)?;
+
+    if out_path.is_null() {
+        return Err(Error::Internal("Export path is null".into()));
+    }
+
+    let path = unsafe { CStr::from_ptr(out_path).to_string_lossy().into_owned() };
+    Ok(PathBuf::from(path))
+}
+
+/// List the platforms vivid can bundle for, marking the ones this host can't build
+pub fn bundle_targets() -> Vec<BundleTarget> {
+    let count = unsafe { vivid_sys::vivid_get_bundle_target_count() };
+    let string = |ptr: *const std::os::raw::c_char| {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned())
+        }
+    };
+
+    (0..count.max(0))
+        .filter_map(|i| {
+            let mut target = vivid_sys::VividBundleTarget {
+                id: ptr::null(),
+                display_name: ptr::null(),
+                available: false,
+                unavailable_reason: ptr::null(),
+            };
+            if !unsafe { vivid_sys::vivid_get_bundle_target(i, &mut target) } {
+                return None;
+            }
+
+            let id = string(target.id)?;
+            Some(BundleTarget {
+                display_name: string(target.display_name).unwrap_or_else(|| id.clone()),
+                id,
+                available: target.available,
+                unavailable_reason: string(target.unavailable_reason),
+            })
+        })
+        .collect()
+}
+
+/// List the example projects shipped under the vivid root's `projects/` directory
+///
+/// Call `configure_asset_paths()` first so vivid knows where the root is;
+/// otherwise the list is empty.
+pub fn example_projects() -> Vec<ExampleProject> {
+    let count = unsafe { vivid_sys::vivid_get_example_project_count() };
+    let string = |ptr: *const std::os::raw::c_char| {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned())
+        }
+    };
+
+    (0..count.max(0))
+        .filter_map(|i| {
+            let mut project = vivid_sys::VividExampleProject {
+                id: ptr::null(),
+                name: ptr::null(),
+                description: ptr::null(),
+                path: ptr::null(),
+            };
+            if !unsafe { vivid_sys::vivid_get_example_project(i, &mut project) } {
+                return None;
+            }
+
+            let id = string(project.id)?;
+            let path = PathBuf::from(string(project.path)?);
+            Some(ExampleProject {
+                name: string(project.name).unwrap_or_else(|| id.clone()),
+                id,
+                description: string(project.description).filter(|d| !d.is_empty()),
+                path,
+            })
+        })
+        .collect()
+}
+
 /// Get the vivid version string
 pub fn version() -> String {
     unsafe {
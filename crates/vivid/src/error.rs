@@ -1,7 +1,9 @@
 //! Error types for the vivid crate
 
-use std::ffi::CStr;
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
 use thiserror::Error;
+use crate::context::ContextDiagnostics;
 use vivid_sys::VividResult;
 
 /// Result type alias for vivid operations
@@ -30,6 +32,10 @@ pub enum Error {
     #[error("No chain loaded")]
     NoChain,
 
+    /// An operation needs a loaded project and there is none
+    #[error("No project loaded")]
+    NoProject,
+
     /// Operator not found
     #[error("Operator not found: {0}")]
     OperatorNotFound(String),
@@ -38,6 +44,14 @@ pub enum Error {
     #[error("Parameter not found: {0}")]
     ParamNotFound(String),
 
+    /// The operator graph contains a cycle through the named operator
+    #[error("Cycle detected at operator: {0}")]
+    CycleDetected(String),
+
+    /// GPU setup failed while creating a windowed context
+    #[error("Context creation failed at {0}")]
+    ContextCreation(ContextDiagnostics),
+
     /// Internal error
     #[error("Internal error: {0}")]
     Internal(String),
@@ -71,6 +85,56 @@ pub fn check_result(result: VividResult) -> Result<()> {
     }
 }
 
+thread_local! {
+    /// Conversion failure recorded by `to_c_string()`, cleared by the next success
+    ///
+    /// Recording one also clears vivid-core's error, so a core error that's set
+    /// afterwards is known to be newer and takes precedence in `last_error()`.
+    static WRAPPER_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Convert a name or value for the C API, failing with the same message
+/// `to_c_string()` records
+///
+/// For APIs that return `Result`, so a NUL byte is reported identically
+/// whichever kind of API hit it.
+pub(crate) fn c_string_arg(what: &str, s: &str) -> Result<CString> {
+    CString::new(s).map_err(|_| Error::InvalidArgument(nul_message(what, s)))
+}
+
+fn nul_message(what: &str, s: &str) -> String {
+    format!("{} contains a NUL byte: {:?}", what, s)
+}
+
+/// Convert a name or value for the C API, recording a NUL byte as the last error
+///
+/// APIs that report failure as `false`/`None` use this so an interior NUL (a
+/// programming error) shows up in `last_error()` instead of looking like
+/// "not found".
+pub(crate) fn to_c_string(what: &str, s: &str) -> Option<CString> {
+    match CString::new(s) {
+        Ok(c) => {
+            WRAPPER_ERROR.with(|e| e.borrow_mut().take());
+            Some(c)
+        }
+        Err(_) => {
+            let message = nul_message(what, s);
+            WRAPPER_ERROR.with(|e| *e.borrow_mut() = Some(message));
+            unsafe { vivid_sys::vivid_clear_error() };
+            None
+        }
+    }
+}
+
+/// Get the most recent error message on this thread
+///
+/// Explains a `false` or `None` from calls that don't return `Result`: either a
+/// name the wrapper rejected (e.g. containing a NUL byte) or vivid-core's last
+/// error, whichever happened most recently.
+pub fn last_error() -> Option<String> {
+    get_last_error().or_else(|| WRAPPER_ERROR.with(|e| e.borrow().clone()))
+}
+
 /// Get the last error message from vivid
 fn get_last_error() -> Option<String> {
     unsafe {
@@ -0,0 +1,212 @@
+//! Batched input for vivid
+
+/// Input accumulated between frames
+///
+/// Hosts collect mouse, key, and scroll events as they arrive and hand the whole
+/// batch to `Context::apply_input_frame` once per frame, instead of making one
+/// call per event. Button and key events keep their arrival order; scroll deltas
+/// are summed and only the latest mouse position is kept.
+#[derive(Debug, Clone, Default)]
+pub struct InputFrame {
+    /// Latest mouse position, if the mouse moved
+    pub mouse_position: Option<(f32, f32)>,
+    /// Mouse button changes as `(button, pressed)`
+    pub mouse_buttons: Vec<(u32, bool)>,
+    /// Key changes as `(keycode, pressed)`
+    pub keys: Vec<(u32, bool)>,
+    /// Accumulated scroll delta
+    pub scroll: (f32, f32),
+}
+
+impl InputFrame {
+    /// Create an empty input frame
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a mouse move
+    pub fn mouse_move(&mut self, x: f32, y: f32) -> &mut Self {
+        self.mouse_position = Some((x, y));
+        self
+    }
+
+    /// Record a mouse button change
+    pub fn mouse_button(&mut self, button: u32, pressed: bool) -> &mut Self {
+        self.mouse_buttons.push((button, pressed));
+        self
+    }
+
+    /// Record a key change
+    pub fn key(&mut self, keycode: u32, pressed: bool) -> &mut Self {
+        self.keys.push((keycode, pressed));
+        self
+    }
+
+    /// Add a scroll delta
+    pub fn scroll(&mut self, dx: f32, dy: f32) -> &mut Self {
+        self.scroll.0 += dx;
+        self.scroll.1 += dy;
+        self
+    }
+
+    /// Check if no input has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.mouse_position.is_none()
+            && self.mouse_buttons.is_empty()
+            && self.keys.is_empty()
+            && self.scroll == (0.0, 0.0)
+    }
+
+    /// Discard all recorded input
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Record a winit window event, ignoring kinds vivid doesn't use
+    ///
+    /// Maps cursor moves (physical pixels), mouse buttons, scroll, and key
+    /// presses. Buttons and keys are translated to GLFW's numbering, which
+    /// vivid-core expects: left/right/middle are 0/1/2 (not winit's or the DOM's
+    /// order), and keys use GLFW key codes; keys without a GLFW equivalent and
+    /// key repeats are dropped. Scroll is in lines, with pixel deltas (e.g. from
+    /// touchpads) converted at `PIXELS_PER_SCROLL_LINE`.
+    #[cfg(feature = "winit")]
+    pub fn from_winit_event(&mut self, event: &winit::event::WindowEvent) -> &mut Self {
+        use winit::event::{ElementState, MouseButton, MouseScrollDelta, WindowEvent};
+        use winit::keyboard::PhysicalKey;
+
+        match event {
+            WindowEvent::CursorMoved { position, .. } => {
+                self.mouse_move(position.x as f32, position.y as f32);
+            }
+            WindowEvent::MouseInput { state, button, .. } => {
+                let button = match button {
+                    MouseButton::Left => 0,
+                    MouseButton::Right => 1,
+                    MouseButton::Middle => 2,
+                    MouseButton::Back => 3,
+                    MouseButton::Forward => 4,
+                    MouseButton::Other(n) => u32::from(*n),
+                };
+                self.mouse_button(button, *state == ElementState::Pressed);
+            }
+            WindowEvent::MouseWheel { delta, .. } => match delta {
+                MouseScrollDelta::LineDelta(dx, dy) => {
+                    self.scroll(*dx, *dy);
+                }
+                MouseScrollDelta::PixelDelta(pos) => {
+                    self.scroll(
+                        (pos.x / PIXELS_PER_SCROLL_LINE) as f32,
+                        (pos.y / PIXELS_PER_SCROLL_LINE) as f32,
+                    );
+                }
+            },
+            WindowEvent::KeyboardInput { event, .. } if !event.repeat => {
+                if let PhysicalKey::Code(code) = event.physical_key {
+                    if let Some(keycode) = glfw_keycode(code) {
+                        self.key(keycode, event.state == ElementState::Pressed);
+                    }
+                }
+            }
+            _ => {}
+        }
+        self
+    }
+}
+
+/// Pixel scroll distance treated as one line by `InputFrame::from_winit_event`
+#[cfg(feature = "winit")]
+pub const PIXELS_PER_SCROLL_LINE: f64 = 20.0;
+
+/// Translate a winit physical key to its GLFW key code
+#[cfg(feature = "winit")]
+fn glfw_keycode(code: winit::keyboard::KeyCode) -> Option<u32> {
+    use winit::keyboard::KeyCode;
+
+    let keycode = match code {
+        KeyCode::Space => 32,
+        KeyCode::Quote => 39,
+        KeyCode::Comma => 44,
+        KeyCode::Minus => 45,
+        KeyCode::Period => 46,
+        KeyCode::Slash => 47,
+        KeyCode::Digit0 => 48,
+        KeyCode::Digit1 => 49,
+        KeyCode::Digit2 => 50,
+        KeyCode::Digit3 => 51,
+        KeyCode::Digit4 => 52,
+        KeyCode::Digit5 => 53,
+        KeyCode::Digit6 => 54,
+        KeyCode::Digit7 => 55,
+        KeyCode::Digit8 => 56,
+        KeyCode::Digit9 => 57,
+        KeyCode::Semicolon => 59,
+        KeyCode::Equal => 61,
+        KeyCode::KeyA => 65,
+        KeyCode::KeyB => 66,
+        KeyCode::KeyC => 67,
+        KeyCode::KeyD => 68,
+        KeyCode::KeyE => 69,
+        KeyCode::KeyF => 70,
+        KeyCode::KeyG => 71,
+        KeyCode::KeyH => 72,
+        KeyCode::KeyI => 73,
+        KeyCode::KeyJ => 74,
+        KeyCode::KeyK => 75,
+        KeyCode::KeyL => 76,
+        KeyCode::KeyM => 77,
+        KeyCode::KeyN => 78,
+        KeyCode::KeyO => 79,
+        KeyCode::KeyP => 80,
+        KeyCode::KeyQ => 81,
+        KeyCode::KeyR => 82,
+        KeyCode::KeyS => 83,
+        KeyCode::KeyT => 84,
+        KeyCode::KeyU => 85,
+        KeyCode::KeyV => 86,
+        KeyCode::KeyW => 87,
+        KeyCode::KeyX => 88,
+        KeyCode::KeyY => 89,
+        KeyCode::KeyZ => 90,
+        KeyCode::BracketLeft => 91,
+        KeyCode::Backslash => 92,
+        KeyCode::BracketRight => 93,
+        KeyCode::Backquote => 96,
+        KeyCode::Escape => 256,
+        KeyCode::Enter => 257,
+        KeyCode::Tab => 258,
+        KeyCode::Backspace => 259,
+        KeyCode::Insert => 260,
+        KeyCode::Delete => 261,
+        KeyCode::ArrowRight => 262,
+        KeyCode::ArrowLeft => 263,
+        KeyCode::ArrowDown => 264,
+        KeyCode::ArrowUp => 265,
+        KeyCode::PageUp => 266,
+        KeyCode::PageDown => 267,
+        KeyCode::Home => 268,
+        KeyCode::End => 269,
+        KeyCode::F1 => 290,
+        KeyCode::F2 => 291,
+        KeyCode::F3 => 292,
+        KeyCode::F4 => 293,
+        KeyCode::F5 => 294,
+        KeyCode::F6 => 295,
+        KeyCode::F7 => 296,
+        KeyCode::F8 => 297,
+        KeyCode::F9 => 298,
+        KeyCode::F10 => 299,
+        KeyCode::F11 => 300,
+        KeyCode::F12 => 301,
+        KeyCode::ShiftLeft => 340,
+        KeyCode::ControlLeft => 341,
+        KeyCode::AltLeft => 342,
+        KeyCode::SuperLeft => 343,
+        KeyCode::ShiftRight => 344,
+        KeyCode::ControlRight => 345,
+        KeyCode::AltRight => 346,
+        KeyCode::SuperRight => 347,
+        _ => return None,
+    };
+    Some(keycode)
+}
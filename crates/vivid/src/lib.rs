@@ -7,9 +7,10 @@
 //! ```no_run
 //! use vivid::{Context, ContextConfig};
 //!
+//! # fn run(device: &wgpu::Device, queue: &wgpu::Queue) -> vivid::Result<()> {
 //! // Create context with external wgpu device
 //! let config = ContextConfig::new(1920, 1080);
-//! let mut ctx = Context::new(&device, &queue, config)?;
+//! let mut ctx = Context::new(device, queue, config)?;
 //!
 //! // Load a project
 //! ctx.load_project("/path/to/project")?;
@@ -19,22 +20,35 @@
 //!     ctx.process_frame(1.0 / 60.0)?;
 //!
 //!     // Get output texture for rendering
-//!     if let Some(view) = ctx.output_view() {
+//!     if let Some(view) = ctx.output_view_raw() {
 //!         // Render the texture...
 //!     }
 //! }
+//! # }
 //! ```
 
 mod context;
 mod chain;
 mod operator;
 mod error;
+mod input;
+mod asset;
+mod shared;
+mod snapshot;
 
-pub use context::{Context, ContextConfig, CompileStatus, version, api_version, configure_asset_paths};
+pub use context::{Context, ContextConfig, AlphaMode, ColorFormat, BundleOptions, BundleTarget, ExampleProject, ContextDiagnostics, CreateStage, CompileStatus, CompileDiagnostic, LoadState, ColorSpace, ImageFormat, LoopFormat, SessionStats, version, api_version, configure_asset_paths, export_standalone, bundle_targets, example_projects};
 pub use chain::Chain;
-pub use operator::{Operator, OutputKind, ParamType, ParamDecl, TextureInfo};
-pub use operator::{RegistryEntry, registry_count, registry_entry, registry_entries};
-pub use error::{Error, Result};
+#[cfg(feature = "petgraph")]
+pub use chain::{OperatorNode, InputEdge};
+pub use operator::{Operator, OutputKind, ParamType, ParamDecl, ParamBinding, Adsr, Color, TextureInfo, ResolutionMismatch, InputBinding, InputDefault, SourceLocation};
+pub use operator::{RegistryEntry, OperatorCost, registry_count, registry_entry, registry_entries, registry_estimated_cost};
+pub use shared::SharedContext;
+pub use snapshot::{ParamSnapshot, ParamPatch, ParamChange};
+pub use input::InputFrame;
+#[cfg(feature = "winit")]
+pub use input::PIXELS_PER_SCROLL_LINE;
+pub use asset::{AssetKind, AssetRef, resolve_asset_path};
+pub use error::{Error, Result, last_error};
 
 /// Re-export vivid-sys for advanced usage
 pub use vivid_sys as ffi;
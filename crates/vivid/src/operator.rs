@@ -1,6 +1,11 @@
 //! Operator management for vivid
 
+use std::collections::{BTreeMap, HashMap};
 use std::ffi::{CStr, CString};
+use std::path::{Path, PathBuf};
+
+use crate::asset::resolve_asset_path;
+use crate::error::{c_string_arg, check_result, to_c_string, Error, Result};
 
 /// Output type classification for operators
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -70,6 +75,64 @@ impl From<vivid_sys::VividParamType> for ParamType {
     }
 }
 
+/// What drives a parameter's value
+///
+/// Anything other than `Static` means vivid-core overwrites the value every
+/// frame, so edits from `set_param()` won't stick.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ParamBinding {
+    /// Holds whatever was last set
+    #[default]
+    Static,
+    /// Evaluated every frame from the given expression
+    Expression(String),
+    /// Mapped to a MIDI controller
+    Midi,
+    /// Driven by an automation curve
+    Automated,
+    /// Set by incoming OSC messages
+    Osc,
+}
+
+impl ParamBinding {
+    /// Whether the value is driven by something other than the user
+    pub fn is_driven(&self) -> bool {
+        !matches!(self, ParamBinding::Static)
+    }
+}
+
+/// What an operator uses in place of an unconnected input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputDefault {
+    /// No substitute; the input is effectively required
+    #[default]
+    None,
+    /// A transparent black texture (or 0 for value inputs)
+    Black,
+    /// An opaque white texture (or 1 for value inputs)
+    White,
+    /// The operator forwards its other input unchanged
+    PassThrough,
+    /// The operator's own previous output (feedback)
+    LastFrame,
+}
+
+impl From<vivid_sys::VividInputDefault> for InputDefault {
+    fn from(d: vivid_sys::VividInputDefault) -> Self {
+        match d {
+            vivid_sys::VividInputDefault::None => InputDefault::None,
+            vivid_sys::VividInputDefault::Black => InputDefault::Black,
+            vivid_sys::VividInputDefault::White => InputDefault::White,
+            vivid_sys::VividInputDefault::PassThrough => InputDefault::PassThrough,
+            vivid_sys::VividInputDefault::LastFrame => InputDefault::LastFrame,
+        }
+    }
+}
+
+/// `(input index, actual (width, height), expected (width, height))`, as
+/// returned by `Operator::input_resolution_mismatch()`
+pub type ResolutionMismatch = (usize, (u32, u32), (u32, u32));
+
 /// Texture information
 #[derive(Debug, Clone)]
 pub struct TextureInfo {
@@ -79,6 +142,169 @@ pub struct TextureInfo {
     pub has_alpha: bool,
 }
 
+impl TextureInfo {
+    /// Short display name for the texture format (e.g. "RGBA16F")
+    ///
+    /// `format` is a WGPUTextureFormat value as defined by wgpu-native's webgpu.h.
+    /// Returns `None` for formats without a short name.
+    pub fn format_name(&self) -> Option<&'static str> {
+        let name = match self.format {
+            0x01 => "R8",
+            0x07 => "R16F",
+            0x08 => "RG8",
+            0x0C => "R32F",
+            0x11 => "RG16F",
+            0x12 => "RGBA8",
+            0x13 => "RGBA8 sRGB",
+            0x17 => "BGRA8",
+            0x18 => "BGRA8 sRGB",
+            0x1A => "RGB10A2",
+            0x1B => "RG11B10F",
+            0x1D => "RG32F",
+            0x22 => "RGBA16F",
+            0x23 => "RGBA32F",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+/// Envelope value of an `Adsr` parameter
+///
+/// Packed into the parameter's four floats in field order:
+///
+/// | index | field     | unit          |
+/// |-------|-----------|---------------|
+/// | 0     | `attack`  | seconds       |
+/// | 1     | `decay`   | seconds       |
+/// | 2     | `sustain` | level (0..=1) |
+/// | 3     | `release` | seconds       |
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Adsr {
+    pub attack: f32,
+    pub decay: f32,
+    pub sustain: f32,
+    pub release: f32,
+}
+
+impl From<[f32; 4]> for Adsr {
+    fn from(value: [f32; 4]) -> Self {
+        Adsr {
+            attack: value[0],
+            decay: value[1],
+            sustain: value[2],
+            release: value[3],
+        }
+    }
+}
+
+impl From<Adsr> for [f32; 4] {
+    fn from(adsr: Adsr) -> Self {
+        [adsr.attack, adsr.decay, adsr.sustain, adsr.release]
+    }
+}
+
+/// Value of a `Color` parameter
+///
+/// Components are sRGB-encoded in `0.0..=1.0`, as written in the project
+/// source and shown by color pickers. Use `to_linear()` before blending or
+/// other math that should happen in linear light.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    /// Parse `#RRGGBB` or `#RRGGBBAA` (the `#` is optional)
+    pub fn from_hex(hex: &str) -> Option<Color> {
+        let hex = hex.trim();
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        // from_str_radix alone would also accept a sign, e.g. "+f"
+        if !matches!(hex.len(), 6 | 8) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let channel = |i: usize| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .ok()
+                .map(|v| v as f32 / 255.0)
+        };
+        Some(Color {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+            a: if hex.len() == 8 { channel(6)? } else { 1.0 },
+        })
+    }
+
+    /// Format as `#rrggbb`, or `#rrggbbaa` when not fully opaque
+    ///
+    /// Components are clamped to `0.0..=1.0` first, so HDR values don't wrap.
+    pub fn to_hex(&self) -> String {
+        let byte = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let rgb = format!("#{:02x}{:02x}{:02x}", byte(self.r), byte(self.g), byte(self.b));
+        if byte(self.a) == 255 {
+            rgb
+        } else {
+            format!("{}{:02x}", rgb, byte(self.a))
+        }
+    }
+
+    /// Convert sRGB-encoded components to linear light (alpha is unchanged)
+    pub fn to_linear(&self) -> Color {
+        fn decode(c: f32) -> f32 {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        Color {
+            r: decode(self.r),
+            g: decode(self.g),
+            b: decode(self.b),
+            a: self.a,
+        }
+    }
+
+    /// Convert linear-light components to sRGB encoding (alpha is unchanged)
+    pub fn to_srgb(&self) -> Color {
+        fn encode(c: f32) -> f32 {
+            if c <= 0.0031308 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        }
+        Color {
+            r: encode(self.r),
+            g: encode(self.g),
+            b: encode(self.b),
+            a: self.a,
+        }
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from(value: [f32; 4]) -> Self {
+        Color {
+            r: value[0],
+            g: value[1],
+            b: value[2],
+            a: value[3],
+        }
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> Self {
+        [color.r, color.g, color.b, color.a]
+    }
+}
+
 /// Parameter declaration
 #[derive(Debug, Clone)]
 pub struct ParamDecl {
@@ -89,15 +315,90 @@ pub struct ParamDecl {
     pub default_val: [f32; 4],
     pub string_default: Option<String>,
     pub enum_labels: Vec<String>,
+    /// Integer value of each enum option, parallel to `enum_labels`
+    ///
+    /// Sparse enums (e.g. `{ Off=0, Low=2, High=4 }`) report their real values;
+    /// otherwise this falls back to the option index.
+    pub enum_values: Vec<i32>,
+    /// UI group/section (e.g., "Transform", "Color"), `None` if ungrouped
+    pub group: Option<String>,
+    /// Display-only (computed) parameter; `set_param` on it returns `false`
+    pub read_only: bool,
+    /// Tooltip text explaining the parameter, if provided
+    pub help: Option<String>,
+    /// Display unit (e.g., "Hz", "px", "deg"), `None` if unitless
+    pub unit: Option<String>,
+    /// Tooltip from the declaration itself, falling back to `help` when
+    /// vivid-core doesn't provide one
+    pub description: Option<String>,
+}
+
+impl ParamDecl {
+    /// Get the integer value for an enum option label
+    pub fn enum_value_for_label(&self, label: &str) -> Option<i32> {
+        self.enum_labels
+            .iter()
+            .position(|l| l == label)
+            .and_then(|i| self.enum_values.get(i).copied())
+    }
+
+    /// Get the option label for an enum integer value
+    pub fn enum_label_for_value(&self, value: i32) -> Option<&str> {
+        self.enum_values
+            .iter()
+            .position(|&v| v == value)
+            .and_then(|i| self.enum_labels.get(i))
+            .map(String::as_str)
+    }
+}
+
+/// Location of an operator declaration in the project source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// Source file path (typically the project's `chain.cpp`)
+    pub file: String,
+    /// 1-based line number
+    pub line: u32,
+    /// 1-based column number
+    pub column: u32,
+}
+
+/// An operator input slot and the operator currently connected to it
+#[derive(Debug, Clone)]
+pub struct InputBinding {
+    /// Input slot index
+    pub index: usize,
+    /// Input slot label (e.g., "input", "mask")
+    pub label: String,
+    /// Name of the connected source operator, if any
+    pub source_name: Option<String>,
+    /// Output kind of the connected source operator, if any
+    pub source_kind: Option<OutputKind>,
+    /// What the operator uses while the slot is unconnected
+    pub default: InputDefault,
 }
 
 /// A reference to a vivid operator
 ///
-/// Operators are owned by the chain. This is a lightweight handle.
+/// Operators are owned by the chain. This is a lightweight handle; cloning it
+/// copies the pointer, not the operator. Every clone is only valid while the
+/// owning chain is alive, i.e. until the project is reloaded or the context is
+/// destroyed.
+#[derive(Clone)]
 pub struct Operator {
     ptr: *mut vivid_sys::VividOperator,
 }
 
+impl std::fmt::Debug for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Operator")
+            .field("name", &self.name())
+            .field("type_name", &self.type_name())
+            .field("output_kind", &self.output_kind())
+            .finish()
+    }
+}
+
 impl Operator {
     /// Create an operator handle from a raw pointer
     pub(crate) fn from_raw(ptr: *mut vivid_sys::VividOperator) -> Self {
@@ -128,6 +429,34 @@ impl Operator {
         }
     }
 
+    /// Get the operator category (as listed in the operator registry)
+    ///
+    /// Returns `"Unknown"` if the core doesn't report a category.
+    pub fn category(&self) -> String {
+        unsafe {
+            let ptr = vivid_sys::vivid_operator_get_category(self.ptr);
+            if ptr.is_null() {
+                String::from("Unknown")
+            } else {
+                CStr::from_ptr(ptr).to_string_lossy().into_owned()
+            }
+        }
+    }
+
+    /// Get the operator help text (what the operator does)
+    ///
+    /// Returns `None` if the operator has no help text.
+    pub fn help_text(&self) -> Option<String> {
+        unsafe {
+            let ptr = vivid_sys::vivid_operator_get_help(self.ptr);
+            if ptr.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+            }
+        }
+    }
+
     /// Get the output kind
     pub fn output_kind(&self) -> OutputKind {
         let kind = unsafe { vivid_sys::vivid_operator_get_output_kind(self.ptr) };
@@ -144,6 +473,89 @@ impl Operator {
         unsafe { vivid_sys::vivid_operator_set_bypassed(self.ptr, bypassed) }
     }
 
+    /// Get the bypass version counter
+    ///
+    /// Incremented whenever the bypass state changes, whether from this handle,
+    /// the visualizer, or another client. Compare against a previously seen value
+    /// to cheaply detect changes without diffing state.
+    pub fn bypass_version(&self) -> u64 {
+        unsafe { vivid_sys::vivid_operator_get_bypass_version(self.ptr) }
+    }
+
+    /// Render this operator at a fraction of the chain resolution
+    ///
+    /// A per-node performance lever, e.g. `0.5` for an expensive blur. The
+    /// operator gets its own texture of `scale` times the chain size (rounded,
+    /// at least 1x1), which `texture_info()` reports. Downstream operators sample
+    /// that smaller texture, upscaled with bilinear filtering, so they see a
+    /// softer but full-size image. Returns `Error::InvalidArgument` unless
+    /// `scale` is in `(0, 1]`; `1.0` restores full resolution.
+    pub fn set_resolution_scale(&mut self, scale: f32) -> Result<()> {
+        if !(scale > 0.0 && scale <= 1.0) {
+            return Err(Error::InvalidArgument(format!(
+                "Resolution scale must be in (0, 1], got {}",
+                scale
+            )));
+        }
+
+        let result = unsafe { vivid_sys::vivid_operator_set_resolution_scale(self.ptr, scale) };
+        check_result(result)
+    }
+
+    /// Get the resolution scale (1.0 = chain resolution)
+    pub fn resolution_scale(&self) -> f32 {
+        unsafe { vivid_sys::vivid_operator_get_resolution_scale(self.ptr) }
+    }
+
+    /// Get the runtime error that isolated this operator, if any
+    ///
+    /// A failing operator outputs black while the rest of the chain keeps running.
+    /// Returns `None` once it processes successfully again.
+    pub fn runtime_error(&self) -> Option<String> {
+        let ptr = unsafe { vivid_sys::vivid_operator_get_runtime_error(self.ptr) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() })
+        }
+    }
+
+    /// Get the position at which the scheduler actually runs this operator
+    ///
+    /// This is the real per-frame execution order, which can differ from both
+    /// registration order and a naive topological sort. Returns `None` for
+    /// operators that aren't scheduled (orphans).
+    pub fn execution_order(&self) -> Option<u32> {
+        let index = unsafe { vivid_sys::vivid_operator_get_exec_order(self.ptr) };
+        u32::try_from(index).ok()
+    }
+
+    /// Get the location where this operator is declared in the project source
+    ///
+    /// Recorded by vivid-core during compilation. Returns `None` for operators
+    /// created programmatically without a source line.
+    pub fn source_location(&self) -> Option<SourceLocation> {
+        let mut loc = vivid_sys::VividSourceLocation {
+            file: std::ptr::null(),
+            line: 0,
+            column: 0,
+        };
+
+        let has_loc = unsafe { vivid_sys::vivid_operator_get_source_location(self.ptr, &mut loc) };
+
+        if !has_loc || loc.file.is_null() || loc.line <= 0 {
+            return None;
+        }
+
+        let file = unsafe { CStr::from_ptr(loc.file).to_string_lossy().into_owned() };
+
+        Some(SourceLocation {
+            file,
+            line: loc.line as u32,
+            column: loc.column.max(1) as u32,
+        })
+    }
+
     /// Get the output texture view (raw pointer)
     ///
     /// Returns `None` if not a texture operator or no output available.
@@ -166,6 +578,19 @@ impl Operator {
         }
     }
 
+    /// Capture this operator's output to a PNG file
+    ///
+    /// Useful for dumping intermediate textures while debugging a chain.
+    /// Non-texture operators return whatever error vivid-core reports.
+    pub fn capture_snapshot<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path_str = path.as_ref().to_string_lossy();
+        let c_path = CString::new(path_str.as_ref())
+            .map_err(|_| Error::InvalidArgument("Invalid path".into()))?;
+
+        let result = unsafe { vivid_sys::vivid_operator_capture_snapshot(self.ptr, c_path.as_ptr()) };
+        check_result(result)
+    }
+
     /// Get texture information
     pub fn texture_info(&self) -> Option<TextureInfo> {
         let mut info = vivid_sys::VividTextureInfo {
@@ -194,6 +619,22 @@ impl Operator {
         unsafe { vivid_sys::vivid_operator_get_output_value(self.ptr) }
     }
 
+    /// Get the output values (for ValueArray operators, e.g. an audio FFT)
+    ///
+    /// Returns `None` for operators that don't output an array. The values are
+    /// copied, so the result stays valid across later `process_frame()` calls.
+    pub fn output_value_array(&self) -> Option<Vec<f32>> {
+        let mut count = 0;
+        let ptr = unsafe { vivid_sys::vivid_operator_get_output_value_array(self.ptr, &mut count) };
+        if ptr.is_null() {
+            return None;
+        }
+        if count <= 0 {
+            return Some(Vec::new());
+        }
+        Some(unsafe { std::slice::from_raw_parts(ptr, count as usize) }.to_vec())
+    }
+
     /// Get the number of parameters
     pub fn param_count(&self) -> usize {
         let count = unsafe { vivid_sys::vivid_operator_get_param_count(self.ptr) };
@@ -211,6 +652,9 @@ impl Operator {
             string_default: std::ptr::null(),
             enum_count: 0,
             enum_labels: std::ptr::null(),
+            read_only: false,
+            unit: std::ptr::null(),
+            description: std::ptr::null(),
         };
 
         let success = unsafe {
@@ -248,6 +692,44 @@ impl Operator {
             labels
         };
 
+        let enum_values = self.param_enum_values(index, enum_labels.len());
+
+        let group = unsafe {
+            let ptr = vivid_sys::vivid_operator_get_param_group(self.ptr, index as i32);
+            if ptr.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+            }
+        }
+        .filter(|g| !g.is_empty());
+
+        let unit = if decl.unit.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(decl.unit).to_string_lossy().into_owned() })
+        }
+        .filter(|u| !u.is_empty());
+
+        let help = unsafe {
+            let ptr = vivid_sys::vivid_operator_get_param_help(self.ptr, index as i32);
+            if ptr.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+            }
+        };
+
+        // Older vivid-core leaves `description` NULL and only serves help text
+        // through the per-index call
+        let description = if decl.description.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(decl.description).to_string_lossy().into_owned() })
+        }
+        .filter(|d| !d.is_empty())
+        .or_else(|| help.clone());
+
         Some(ParamDecl {
             name,
             param_type: decl.param_type.into(),
@@ -256,9 +738,47 @@ impl Operator {
             default_val: decl.default_val,
             string_default,
             enum_labels,
+            enum_values,
+            group,
+            read_only: decl.read_only,
+            help,
+            unit,
+            description,
         })
     }
 
+    /// Find a parameter's declaration index and declaration by name
+    ///
+    /// Stops at the first match instead of building every declaration.
+    fn find_param_decl(&self, name: &str) -> Option<(usize, ParamDecl)> {
+        (0..self.param_count())
+            .filter_map(|index| Some((index, self.param_decl(index)?)))
+            .find(|(_, decl)| decl.name == name)
+    }
+
+    /// Get the integer values of an enum parameter's options, falling back to indices
+    fn param_enum_values(&self, index: usize, label_count: usize) -> Vec<i32> {
+        if label_count == 0 {
+            return Vec::new();
+        }
+
+        let mut values = vec![0; label_count];
+        let count = unsafe {
+            vivid_sys::vivid_operator_get_param_enum_values(
+                self.ptr,
+                index as i32,
+                values.as_mut_ptr(),
+                label_count as i32,
+            )
+        };
+
+        if count as usize != label_count {
+            // Values not provided (or don't line up with the labels): index == value
+            return (0..label_count as i32).collect();
+        }
+        values
+    }
+
     /// Get all parameter declarations
     pub fn params(&self) -> Vec<ParamDecl> {
         (0..self.param_count())
@@ -270,7 +790,7 @@ impl Operator {
     ///
     /// Returns `None` if the parameter doesn't exist.
     pub fn get_param(&self, name: &str) -> Option<[f32; 4]> {
-        let c_name = CString::new(name).ok()?;
+        let c_name = to_c_string("Parameter name", name)?;
         let mut value = [0.0f32; 4];
 
         let success = unsafe {
@@ -284,18 +804,451 @@ impl Operator {
         }
     }
 
+    /// Get the current value of every numeric parameter, keyed by name
+    ///
+    /// Walks the declarations once and reads each value by index, skipping any
+    /// that can't be read. String and file-path parameters have no `[f32; 4]`
+    /// value and are left out (use `get_param_string()` for those). Ordered by
+    /// name, so the result diffs and serializes deterministically (e.g. for
+    /// presets).
+    pub fn param_values(&self) -> BTreeMap<String, [f32; 4]> {
+        (0..self.param_count())
+            .filter_map(|index| {
+                let decl = self.param_decl(index)?;
+                if matches!(decl.param_type, ParamType::String | ParamType::FilePath) {
+                    return None;
+                }
+                Some((decl.name, self.get_param_by_index(index)?))
+            })
+            .collect()
+    }
+
+    /// Set several parameters at once, e.g. from a preset saved by `param_values()`
+    ///
+    /// Declarations are resolved once and each value is set by index. Every
+    /// entry is attempted even if some fail; returns the names that couldn't be
+    /// applied (unknown, string-typed, or rejected by vivid-core, e.g. read-only)
+    /// in name order, so a preset saved against an older chain can report its
+    /// mismatches. Values that did apply stay applied.
+    pub fn apply_params(&mut self, values: &BTreeMap<String, [f32; 4]>) -> Vec<String> {
+        let decls: HashMap<String, (usize, ParamType)> = (0..self.param_count())
+            .filter_map(|index| {
+                let decl = self.param_decl(index)?;
+                Some((decl.name, (index, decl.param_type)))
+            })
+            .collect();
+
+        let mut failed = Vec::new();
+        for (name, value) in values {
+            let applied = match decls.get(name) {
+                Some(&(_, ParamType::String | ParamType::FilePath)) | None => false,
+                Some(&(index, _)) => self.set_param_by_index(index, value),
+            };
+            if !applied {
+                failed.push(name.clone());
+            }
+        }
+        failed
+    }
+
+    /// Get a parameter value by declaration index
+    ///
+    /// Avoids the string allocation and name lookup of `get_param()`; resolve names
+    /// to indices once (via `params()`) and drive by index for per-frame automation.
+    /// Indices are only stable until the chain is reloaded.
+    pub fn get_param_by_index(&self, index: usize) -> Option<[f32; 4]> {
+        let mut value = [0.0f32; 4];
+
+        let success = unsafe {
+            vivid_sys::vivid_operator_get_param_indexed(self.ptr, index as i32, value.as_mut_ptr())
+        };
+
+        if success {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Set a parameter value by declaration index
+    ///
+    /// Returns `true` if successful. See `get_param_by_index()`.
+    pub fn set_param_by_index(&mut self, index: usize, value: &[f32; 4]) -> bool {
+        unsafe { vivid_sys::vivid_operator_set_param_indexed(self.ptr, index as i32, value.as_ptr()) }
+    }
+
+    /// Get a `Float` parameter
+    ///
+    /// The typed getters check the declared `ParamType` and return `None` if the
+    /// parameter doesn't exist or is declared with a different type.
+    pub fn get_param_f32(&self, name: &str) -> Option<f32> {
+        let value = self.get_param_typed(name, |ty| ty == ParamType::Float)?;
+        Some(value[0])
+    }
+
+    /// Get an `Int` or `Enum` parameter, rounding the stored float
+    pub fn get_param_i32(&self, name: &str) -> Option<i32> {
+        let value =
+            self.get_param_typed(name, |ty| matches!(ty, ParamType::Int | ParamType::Enum))?;
+        Some(value[0].round() as i32)
+    }
+
+    /// Get a `Bool` parameter (any non-zero value is `true`)
+    pub fn get_param_bool(&self, name: &str) -> Option<bool> {
+        let value = self.get_param_typed(name, |ty| ty == ParamType::Bool)?;
+        Some(value[0] != 0.0)
+    }
+
+    /// Get a `Vec2` parameter
+    pub fn get_param_vec2(&self, name: &str) -> Option<(f32, f32)> {
+        let value = self.get_param_typed(name, |ty| ty == ParamType::Vec2)?;
+        Some((value[0], value[1]))
+    }
+
+    /// Get a `Vec3` parameter
+    pub fn get_param_vec3(&self, name: &str) -> Option<(f32, f32, f32)> {
+        let value = self.get_param_typed(name, |ty| ty == ParamType::Vec3)?;
+        Some((value[0], value[1], value[2]))
+    }
+
+    /// Get a `Vec4` or `Color` parameter
+    pub fn get_param_vec4(&self, name: &str) -> Option<(f32, f32, f32, f32)> {
+        let value =
+            self.get_param_typed(name, |ty| matches!(ty, ParamType::Vec4 | ParamType::Color))?;
+        Some((value[0], value[1], value[2], value[3]))
+    }
+
+    /// Get a `Color` parameter
+    ///
+    /// Unlike `get_param_vec4()`, this only accepts parameters declared as colors.
+    pub fn get_param_color(&self, name: &str) -> Option<Color> {
+        let value = self.get_param_typed(name, |ty| ty == ParamType::Color)?;
+        Some(value.into())
+    }
+
+    /// Set a `Color` parameter
+    ///
+    /// Returns `false` if the parameter doesn't exist or isn't a color.
+    pub fn set_param_color(&mut self, name: &str, color: &Color) -> bool {
+        match self.find_param_decl(name) {
+            Some((index, decl)) if decl.param_type == ParamType::Color => {
+                self.set_param_by_index(index, &(*color).into())
+            }
+            _ => false,
+        }
+    }
+
+    /// Get an `Adsr` parameter
+    pub fn get_param_adsr(&self, name: &str) -> Option<Adsr> {
+        let value = self.get_param_typed(name, |ty| ty == ParamType::Adsr)?;
+        Some(value.into())
+    }
+
+    /// Set an `Adsr` parameter
+    ///
+    /// Returns `false` if the parameter doesn't exist or isn't an envelope.
+    pub fn set_param_adsr(&mut self, name: &str, adsr: &Adsr) -> bool {
+        match self.find_param_decl(name) {
+            Some((index, decl)) if decl.param_type == ParamType::Adsr => {
+                self.set_param_by_index(index, &(*adsr).into())
+            }
+            _ => false,
+        }
+    }
+
+    /// Set an `Enum` parameter by option label
+    ///
+    /// Returns `false` if the parameter isn't an enum or has no option with this
+    /// label, so an out-of-range value is never written.
+    pub fn set_param_enum(&mut self, name: &str, label: &str) -> bool {
+        let Some((index, decl)) = self.find_param_decl(name) else {
+            return false;
+        };
+        if decl.param_type != ParamType::Enum {
+            return false;
+        }
+
+        match decl.enum_value_for_label(label) {
+            Some(value) => self.set_param_by_index(index, &[value as f32, 0.0, 0.0, 0.0]),
+            None => false,
+        }
+    }
+
+    /// Get the label of an `Enum` parameter's current option
+    ///
+    /// Returns `None` if the parameter isn't an enum or its value matches no option.
+    pub fn get_param_enum_label(&self, name: &str) -> Option<String> {
+        let (index, decl) = self.find_param_decl(name)?;
+        if decl.param_type != ParamType::Enum {
+            return None;
+        }
+        let value = self.get_param_by_index(index)?[0].round() as i32;
+        decl.enum_label_for_value(value).map(str::to_owned)
+    }
+
+    /// Get a parameter's raw value if its declared type passes `accepts`
+    fn get_param_typed(&self, name: &str, accepts: impl Fn(ParamType) -> bool) -> Option<[f32; 4]> {
+        let (index, decl) = self.find_param_decl(name)?;
+        if !accepts(decl.param_type) {
+            return None;
+        }
+        self.get_param_by_index(index)
+    }
+
+    /// Get the value a parameter had when the project was loaded
+    ///
+    /// This is the value written in the project source (e.g. `chain.cpp`), which
+    /// may differ both from the operator's built-in default and from the current
+    /// value after edits in the IDE. Returns `None` if the parameter doesn't exist.
+    pub fn saved_param(&self, name: &str) -> Option<[f32; 4]> {
+        let c_name = to_c_string("Parameter name", name)?;
+        let mut value = [0.0f32; 4];
+
+        let success = unsafe {
+            vivid_sys::vivid_operator_get_saved_param(self.ptr, c_name.as_ptr(), value.as_mut_ptr())
+        };
+
+        if success {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Get the value a string or file path parameter had when the project was loaded
+    ///
+    /// The string counterpart of `saved_param()`. Returns `None` if the parameter
+    /// doesn't exist or isn't string-typed.
+    pub fn saved_param_string(&self, name: &str) -> Option<String> {
+        let c_name = to_c_string("Parameter name", name)?;
+        let ptr = unsafe {
+            vivid_sys::vivid_operator_get_saved_param_string(self.ptr, c_name.as_ptr())
+        };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() })
+        }
+    }
+
+    /// Reset a parameter to the value saved in the project source
+    ///
+    /// Undoes runtime edits, restoring what `chain.cpp` set when the project was
+    /// loaded, for string and file-path parameters too. Use
+    /// [`Operator::reset_param_to_default`] to go back to the operator's built-in
+    /// default instead. Returns `true` if successful.
+    pub fn reset_param_to_saved(&mut self, name: &str) -> bool {
+        let Some((index, decl)) = self.find_param_decl(name) else {
+            return false;
+        };
+        match decl.param_type {
+            ParamType::String | ParamType::FilePath => match self.saved_param_string(name) {
+                Some(value) => self.set_param_string(name, &value),
+                None => false,
+            },
+            _ => match self.saved_param(name) {
+                Some(value) => self.set_param_by_index(index, &value),
+                None => false,
+            },
+        }
+    }
+
+    /// Reset a parameter to the operator's built-in default
+    ///
+    /// Ignores whatever `chain.cpp` set; see [`Operator::reset_param_to_saved`] for
+    /// restoring the project's value. String and file-path parameters go back to
+    /// `ParamDecl::string_default` (empty if there is none). Returns `true` if
+    /// successful.
+    pub fn reset_param_to_default(&mut self, name: &str) -> bool {
+        match self.find_param_decl(name) {
+            Some((index, decl)) => self.write_default(index, &decl),
+            None => false,
+        }
+    }
+
+    /// Reset a parameter to its declared default
+    ///
+    /// Same as [`Operator::reset_param_to_default`]; this is what an inspector's
+    /// "reset" button wants.
+    pub fn reset_param(&mut self, name: &str) -> bool {
+        self.reset_param_to_default(name)
+    }
+
+    /// Reset every writable parameter to the operator's built-in default
+    ///
+    /// Read-only parameters are skipped. Returns the names that vivid-core
+    /// rejected, in declaration order; the rest stay reset. Like
+    /// `apply_params()`, a partial failure is reported rather than hidden, so
+    /// a UI can say which values didn't reset; callers that don't care can
+    /// ignore the result.
+    pub fn reset_all_params(&mut self) -> Vec<String> {
+        let mut failed = Vec::new();
+        for index in 0..self.param_count() {
+            let Some(decl) = self.param_decl(index) else {
+                continue;
+            };
+            if !decl.read_only && !self.write_default(index, &decl) {
+                failed.push(decl.name);
+            }
+        }
+        failed
+    }
+
+    /// Write a declaration's default value back to its parameter
+    fn write_default(&mut self, index: usize, decl: &ParamDecl) -> bool {
+        match decl.param_type {
+            ParamType::String | ParamType::FilePath => {
+                let value = decl.string_default.as_deref().unwrap_or("");
+                self.set_param_string(&decl.name, value)
+            }
+            _ => self.set_param_by_index(index, &decl.default_val),
+        }
+    }
+
     /// Set a parameter value
     ///
-    /// Returns `true` if successful.
+    /// Returns `true` if successful. Read-only parameters (see
+    /// `ParamDecl::read_only`) are rejected by vivid-core and return `false`.
+    /// On failure, `last_error()` says why, including a name with a NUL byte.
     pub fn set_param(&mut self, name: &str, value: &[f32; 4]) -> bool {
-        let c_name = match CString::new(name) {
-            Ok(s) => s,
-            Err(_) => return false,
+        let Some(c_name) = to_c_string("Parameter name", name) else {
+            return false;
         };
 
         unsafe { vivid_sys::vivid_operator_set_param(self.ptr, c_name.as_ptr(), value.as_ptr()) }
     }
 
+    /// Drive a parameter from an expression instead of a static value
+    ///
+    /// vivid-core re-evaluates the expression every frame, so it can reference
+    /// other operators' outputs and parameters (e.g. `"brightness * 2"`), making a
+    /// chain reactive without extra operators. While an expression is set,
+    /// `set_param()` on the parameter is overridden on the next frame.
+    ///
+    /// Returns `Error::CompileFailed` with the parser's message if the expression
+    /// is invalid, and `Error::ParamNotFound` for unknown parameters.
+    pub fn set_param_expression(&mut self, name: &str, expr: &str) -> Result<()> {
+        let c_name = c_string_arg("Parameter name", name)?;
+        let c_expr = c_string_arg("Expression", expr)?;
+
+        let result = unsafe {
+            vivid_sys::vivid_operator_set_param_expression(
+                self.ptr,
+                c_name.as_ptr(),
+                c_expr.as_ptr(),
+            )
+        };
+        check_result(result)
+    }
+
+    /// Remove a parameter's expression, reverting it to a static value
+    ///
+    /// The parameter keeps the value the expression last produced.
+    pub fn clear_param_expression(&mut self, name: &str) -> Result<()> {
+        let c_name = c_string_arg("Parameter name", name)?;
+
+        let result =
+            unsafe { vivid_sys::vivid_operator_clear_param_expression(self.ptr, c_name.as_ptr()) };
+        check_result(result)
+    }
+
+    /// Get the expression driving a parameter
+    ///
+    /// Returns `None` if the parameter has a static value or doesn't exist.
+    pub fn param_expression(&self, name: &str) -> Option<String> {
+        let c_name = to_c_string("Parameter name", name)?;
+        let ptr =
+            unsafe { vivid_sys::vivid_operator_get_param_expression(self.ptr, c_name.as_ptr()) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() })
+        }
+    }
+
+    /// Get a parameter's change counter
+    ///
+    /// Increases monotonically whenever the value changes, whoever changed it:
+    /// `set_param()`, an expression, MIDI, or automation inside the chain. Poll it
+    /// and re-read only parameters whose generation moved. See
+    /// `Chain::param_version()` for a single chain-wide counter. Returns `None`
+    /// if the parameter doesn't exist.
+    pub fn param_generation(&self, name: &str) -> Option<u64> {
+        let c_name = to_c_string("Parameter name", name)?;
+        let generation =
+            unsafe { vivid_sys::vivid_operator_get_param_generation(self.ptr, c_name.as_ptr()) };
+        (generation != 0).then_some(generation)
+    }
+
+    /// Get what drives a parameter's value
+    ///
+    /// Returns `ParamBinding::Static` for unbound or unknown parameters.
+    pub fn param_binding(&self, name: &str) -> ParamBinding {
+        let Some(c_name) = to_c_string("Parameter name", name) else {
+            return ParamBinding::Static;
+        };
+
+        let binding =
+            unsafe { vivid_sys::vivid_operator_get_param_binding(self.ptr, c_name.as_ptr()) };
+        match binding {
+            vivid_sys::VividParamBinding::Static => ParamBinding::Static,
+            vivid_sys::VividParamBinding::Expression => {
+                ParamBinding::Expression(self.param_expression(name).unwrap_or_default())
+            }
+            vivid_sys::VividParamBinding::Midi => ParamBinding::Midi,
+            vivid_sys::VividParamBinding::Automated => ParamBinding::Automated,
+            vivid_sys::VividParamBinding::Osc => ParamBinding::Osc,
+        }
+    }
+
+    /// Get a string or file path parameter value
+    ///
+    /// Returns `None` if the parameter doesn't exist or isn't string-typed.
+    /// File paths are returned as written, which may be relative to the project.
+    pub fn get_param_string(&self, name: &str) -> Option<String> {
+        let (_, decl) = self.find_param_decl(name)?;
+        if !matches!(decl.param_type, ParamType::String | ParamType::FilePath) {
+            return None;
+        }
+
+        let c_name = to_c_string("Parameter name", name)?;
+        let ptr = unsafe { vivid_sys::vivid_operator_get_param_string(self.ptr, c_name.as_ptr()) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() })
+        }
+    }
+
+    /// Resolve a file path parameter to an absolute path
+    ///
+    /// Relative paths are joined onto `project_root`, a leading `~` is expanded to
+    /// the home directory, and absolute paths are returned unchanged. Returns `None`
+    /// if the parameter doesn't exist or is empty.
+    pub fn resolve_file_param(&self, name: &str, project_root: &Path) -> Option<PathBuf> {
+        let raw = self.get_param_string(name)?;
+        if raw.is_empty() {
+            return None;
+        }
+        Some(resolve_asset_path(&raw, project_root))
+    }
+
+    /// Set a string or file path parameter value
+    ///
+    /// Returns `true` if successful.
+    pub fn set_param_string(&mut self, name: &str, value: &str) -> bool {
+        let Some(c_name) = to_c_string("Parameter name", name) else {
+            return false;
+        };
+        let Some(c_value) = to_c_string("Parameter value", value) else {
+            return false;
+        };
+
+        unsafe {
+            vivid_sys::vivid_operator_set_param_string(self.ptr, c_name.as_ptr(), c_value.as_ptr())
+        }
+    }
+
     /// Set a float parameter
     pub fn set_param_float(&mut self, name: &str, value: f32) -> bool {
         self.set_param(name, &[value, 0.0, 0.0, 0.0])
@@ -344,12 +1297,101 @@ impl Operator {
         }
     }
 
+    /// Get what this operator uses when input `index` is unconnected
+    ///
+    /// Returns `InputDefault::None` for out-of-range indices. The answer is
+    /// per slot, not per operator type, so check each input separately.
+    pub fn input_default_behavior(&self, index: usize) -> InputDefault {
+        let Ok(index) = i32::try_from(index) else {
+            return InputDefault::None;
+        };
+        unsafe { vivid_sys::vivid_operator_get_input_default(self.ptr, index) }.into()
+    }
+
+    /// Get all input slots with their labels and connected sources
+    pub fn inputs(&self) -> Vec<InputBinding> {
+        (0..self.input_count())
+            .map(|index| {
+                let source = self.input(index);
+                InputBinding {
+                    index,
+                    label: self.input_name(index),
+                    source_name: source.as_ref().map(|op| op.name()),
+                    source_kind: source.as_ref().map(|op| op.output_kind()),
+                    default: self.input_default_behavior(index),
+                }
+            })
+            .collect()
+    }
+
+    /// Iterate over connected inputs as `(slot name, source operator)` pairs
+    ///
+    /// Handy for walking the graph upstream. Unconnected slots are skipped; use
+    /// `inputs()` to see every slot including empty ones.
+    pub fn input_iter(&self) -> InputIterator<'_> {
+        InputIterator {
+            op: self,
+            index: 0,
+            count: self.input_count(),
+        }
+    }
+
+    /// Find texture inputs whose size differs from this operator's output
+    ///
+    /// Returns `(input index, actual size, expected size)` for each mismatch,
+    /// where sizes are `(width, height)` and the expected size is this
+    /// operator's own texture. vivid-core rescales such inputs silently, which
+    /// can soften the result (e.g. a 512x512 input feeding a 1920x1080
+    /// operator). Unconnected and non-texture inputs are skipped; the result is
+    /// empty if everything matches or this operator has no texture.
+    pub fn input_resolution_mismatch(&self) -> Vec<ResolutionMismatch> {
+        let Some(own) = self.texture_info() else {
+            return Vec::new();
+        };
+        let expected = (own.width, own.height);
+
+        (0..self.input_count())
+            .filter_map(|index| {
+                let info = self.input(index)?.texture_info()?;
+                let actual = (info.width, info.height);
+                (actual != expected).then_some((index, actual, expected))
+            })
+            .collect()
+    }
+
     /// Get the raw operator pointer
     pub fn as_raw(&self) -> *mut vivid_sys::VividOperator {
         self.ptr
     }
 }
 
+/// Iterator over an operator's connected inputs
+pub struct InputIterator<'a> {
+    op: &'a Operator,
+    index: usize,
+    count: usize,
+}
+
+impl<'a> Iterator for InputIterator<'a> {
+    type Item = (String, Operator);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.count {
+            let index = self.index;
+            self.index += 1;
+            if let Some(source) = self.op.input(index) {
+                return Some((self.op.input_name(index), source));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Unconnected slots are skipped, so the remaining slot count is only an upper bound
+        (0, Some(self.count - self.index))
+    }
+}
+
 // =============================================================================
 // Operator Registry
 // =============================================================================
@@ -359,6 +1401,7 @@ impl Operator {
 pub struct RegistryEntry {
     pub name: String,
     pub category: String,
+    pub help: Option<String>,
 }
 
 /// Get the number of registered operator types
@@ -390,7 +1433,16 @@ pub fn registry_entry(index: usize) -> Option<RegistryEntry> {
         }
     };
 
-    Some(RegistryEntry { name, category })
+    let help = unsafe {
+        let ptr = vivid_sys::vivid_registry_get_operator_help(index as i32);
+        if ptr.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+        }
+    };
+
+    Some(RegistryEntry { name, category, help })
 }
 
 /// Get all registered operator types
@@ -399,3 +1451,29 @@ pub fn registry_entries() -> Vec<RegistryEntry> {
         .filter_map(registry_entry)
         .collect()
 }
+
+/// Coarse cost estimate for an operator type
+///
+/// This is guidance for flagging expensive nodes in the UI, not a promise about
+/// actual frame time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperatorCost {
+    Light,
+    Medium,
+    Heavy,
+}
+
+/// Get the estimated cost of a registered operator type
+///
+/// Returns `Medium` if the type is unknown or has no cost metadata.
+pub fn registry_estimated_cost(type_name: &str) -> OperatorCost {
+    let Some(c_name) = to_c_string("Operator type", type_name) else {
+        return OperatorCost::Medium;
+    };
+
+    match unsafe { vivid_sys::vivid_registry_get_operator_cost(c_name.as_ptr()) } {
+        0 => OperatorCost::Light,
+        2 => OperatorCost::Heavy,
+        _ => OperatorCost::Medium,
+    }
+}
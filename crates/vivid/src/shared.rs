@@ -0,0 +1,75 @@
+//! A context that can be shared between threads
+
+use std::sync::{Mutex, MutexGuard, TryLockError};
+
+use crate::context::Context;
+
+/// A `Context` behind a mutex, for hosts that touch it from several threads
+///
+/// `Context` is `Send` but not `Sync`: vivid-core is single-threaded, so only one
+/// thread may use it at a time. `SharedContext` is `Send + Sync` (wrap it in an
+/// `Arc`) and serializes every access through `with()`/`with_mut()`.
+///
+/// Locking only prevents concurrent calls; it doesn't move the work to another
+/// thread. Contexts created with `Context::with_window()` must still call
+/// `render_frame()` from the thread that owns the window/surface (the main
+/// thread on macOS), so keep rendering there and use the other threads for
+/// queries and edits.
+///
+/// A panic inside a closure poisons the mutex. It unwinds out of Rust code
+/// between calls into vivid-core, so the context is still usable and later calls
+/// recover the lock instead of panicking too.
+pub struct SharedContext {
+    inner: Mutex<Context>,
+}
+
+impl SharedContext {
+    /// Wrap a context for shared use
+    pub fn new(ctx: Context) -> Self {
+        Self {
+            inner: Mutex::new(ctx),
+        }
+    }
+
+    /// Run `f` with shared access to the context, blocking until it's free
+    pub fn with<T>(&self, f: impl FnOnce(&Context) -> T) -> T {
+        f(&self.lock())
+    }
+
+    /// Run `f` with mutable access to the context, blocking until it's free
+    pub fn with_mut<T>(&self, f: impl FnOnce(&mut Context) -> T) -> T {
+        f(&mut self.lock())
+    }
+
+    /// Run `f` only if the context is free right now
+    ///
+    /// Returns `None` without waiting if another thread holds it, e.g. so a
+    /// stats poll doesn't stall behind a long render.
+    pub fn try_with<T>(&self, f: impl FnOnce(&Context) -> T) -> Option<T> {
+        let guard = match self.inner.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => return None,
+        };
+        Some(f(&guard))
+    }
+
+    /// Unwrap the context, e.g. to drop it on a particular thread
+    pub fn into_inner(self) -> Context {
+        self.inner
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Context> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl From<Context> for SharedContext {
+    fn from(ctx: Context) -> Self {
+        Self::new(ctx)
+    }
+}
@@ -0,0 +1,93 @@
+//! Parameter snapshots and patches for undo/redo
+
+use std::collections::BTreeMap;
+
+/// The numeric parameter values of every operator in a chain
+///
+/// Taken with `Chain::param_snapshot()`. Keyed by operator name, then parameter
+/// name, so two snapshots compare and serialize deterministically. String and
+/// file-path parameters are not included.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParamSnapshot {
+    pub values: BTreeMap<String, BTreeMap<String, [f32; 4]>>,
+}
+
+impl ParamSnapshot {
+    /// Get one parameter's value, if the snapshot has it
+    pub fn get(&self, operator: &str, param: &str) -> Option<[f32; 4]> {
+        self.values.get(operator)?.get(param).copied()
+    }
+
+    /// Build the patch that turns `self` into `other`
+    ///
+    /// Only parameters present in both snapshots with different values are
+    /// recorded. Snapshot before and after a slider drag to get the whole drag
+    /// as a single undo step.
+    pub fn diff(&self, other: &ParamSnapshot) -> ParamPatch {
+        let mut changes = Vec::new();
+        for (operator, params) in &self.values {
+            let Some(other_params) = other.values.get(operator) else {
+                continue;
+            };
+            for (param, &before) in params {
+                match other_params.get(param) {
+                    Some(&after) if after != before => changes.push(ParamChange {
+                        operator: operator.clone(),
+                        param: param.clone(),
+                        before,
+                        after,
+                    }),
+                    _ => {}
+                }
+            }
+        }
+        ParamPatch { changes }
+    }
+}
+
+/// One parameter's value before and after a change
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParamChange {
+    pub operator: String,
+    pub param: String,
+    pub before: [f32; 4],
+    pub after: [f32; 4],
+}
+
+/// A set of parameter changes, applied with `Chain::apply_patch()`
+///
+/// Applying writes each change's `after` value; apply `inverse()` to undo.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParamPatch {
+    pub changes: Vec<ParamChange>,
+}
+
+impl ParamPatch {
+    /// Get the patch that reverts this one
+    pub fn inverse(&self) -> ParamPatch {
+        let changes = self
+            .changes
+            .iter()
+            .map(|c| ParamChange {
+                operator: c.operator.clone(),
+                param: c.param.clone(),
+                before: c.after,
+                after: c.before,
+            })
+            .collect();
+        ParamPatch { changes }
+    }
+
+    /// Whether the patch changes nothing
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Get the number of parameter changes
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+}
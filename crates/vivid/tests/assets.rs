@@ -0,0 +1,52 @@
+//! Asset path resolution (no GPU needed)
+
+use std::path::{Path, PathBuf};
+
+use vivid::{resolve_asset_path, AssetKind};
+
+#[test]
+fn relative_paths_join_the_project_root() {
+    let root = Path::new("/projects/demo");
+    assert_eq!(
+        resolve_asset_path("textures/noise.png", root),
+        PathBuf::from("/projects/demo/textures/noise.png")
+    );
+    assert_eq!(resolve_asset_path("", root), root);
+}
+
+#[cfg(unix)]
+#[test]
+fn absolute_paths_are_unchanged() {
+    let root = Path::new("/projects/demo");
+    assert_eq!(resolve_asset_path("/media/clip.mov", root), PathBuf::from("/media/clip.mov"));
+}
+
+#[cfg(unix)]
+#[test]
+fn backslashes_are_normalized() {
+    let root = Path::new("/projects/demo");
+    assert_eq!(
+        resolve_asset_path("textures\\noise.png", root),
+        PathBuf::from("/projects/demo/textures/noise.png")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn tilde_expands_to_home() {
+    let root = Path::new("/projects/demo");
+    let home = PathBuf::from(std::env::var_os("HOME").expect("HOME is not set"));
+
+    assert_eq!(resolve_asset_path("~/clips/a.mov", root), home.join("clips/a.mov"));
+    assert_eq!(resolve_asset_path("~", root), home);
+
+    // Only a leading "~" or "~/" means home; "~user" is an ordinary name
+    assert_eq!(resolve_asset_path("~user/a.mov", root), root.join("~user/a.mov"));
+}
+
+#[test]
+fn kinds_follow_the_extension() {
+    assert_eq!(AssetKind::from_path(Path::new("a.PNG")), AssetKind::Image);
+    assert_eq!(AssetKind::from_path(Path::new("clip.mov")), AssetKind::Video);
+    assert_eq!(AssetKind::from_path(Path::new("notes")), AssetKind::Other);
+}
@@ -0,0 +1,93 @@
+//! Shared setup for the GPU integration tests
+
+// Each test binary includes this module but uses only some of the helpers
+#![allow(dead_code)]
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll, Wake, Waker};
+
+use vivid::{Chain, Context, ContextConfig, Operator, ParamDecl, ParamType};
+
+/// Minimal executor for wgpu's adapter/device futures
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = TaskContext::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+/// A context with `VIVID_TEST_PROJECT` loaded
+///
+/// Fields drop in declaration order, so the context is destroyed before the
+/// device and queue it was created with.
+pub struct TestProject {
+    pub ctx: Context,
+    _device: wgpu::Device,
+    _queue: wgpu::Queue,
+}
+
+pub fn load_test_project() -> TestProject {
+    let project = std::env::var("VIVID_TEST_PROJECT").expect("VIVID_TEST_PROJECT not set");
+
+    let instance = wgpu::Instance::default();
+    let adapter = block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+        .expect("no GPU adapter");
+    let (device, queue) = block_on(adapter.request_device(&wgpu::DeviceDescriptor::default(), None))
+        .expect("failed to create device");
+
+    let mut ctx = Context::new(&device, &queue, ContextConfig::new(256, 256)).unwrap();
+    ctx.load_project(&project).unwrap();
+    TestProject {
+        ctx,
+        _device: device,
+        _queue: queue,
+    }
+}
+
+/// Find the first parameter in the chain matching `pred`
+///
+/// Panics naming `what` if the test project has none, so a project that can't
+/// exercise a test fails it instead of passing vacuously.
+pub fn find_param(
+    chain: &Chain,
+    what: &str,
+    pred: impl Fn(&ParamDecl) -> bool,
+) -> (Operator, ParamDecl) {
+    chain
+        .operators()
+        .find_map(|op| {
+            let param = op.params().into_iter().find(|p| pred(p))?;
+            Some((op, param))
+        })
+        .unwrap_or_else(|| panic!("test project has no {what} parameter"))
+}
+
+/// Find the first writable `Float` parameter
+pub fn writable_float_param(chain: &Chain) -> (Operator, ParamDecl) {
+    find_param(chain, "writable Float", |p| p.param_type == ParamType::Float && !p.read_only)
+}
+
+/// A value for `param` that differs from its current value on `op`
+pub fn toggled_float(op: &Operator, param: &ParamDecl) -> f32 {
+    let value = op.get_param_f32(&param.name).expect("parameter has no value");
+    if value == param.max_val {
+        param.min_val
+    } else {
+        param.max_val
+    }
+}
@@ -0,0 +1,99 @@
+//! Graph export against a real vivid-core
+//!
+//! ```text
+//! VIVID_TEST_PROJECT=/path/to/project cargo test -p vivid -- --ignored
+//! ```
+
+mod common;
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn dot_has_a_node_per_operator_and_an_edge_per_input() {
+    let project = common::load_test_project();
+    let chain = project.ctx.chain().expect("project has no chain");
+
+    let dot = chain.to_dot();
+    assert!(dot.starts_with("digraph chain {"));
+    assert!(dot.trim_end().ends_with('}'));
+
+    let nodes = dot.lines().filter(|l| l.contains("[label=") && !l.contains("->")).count();
+    let edges = dot.lines().filter(|l| l.contains(" -> ")).count();
+    let connected_inputs: usize = chain.operators().map(|op| op.input_iter().count()).sum();
+
+    assert_eq!(nodes, chain.operator_count());
+    assert_eq!(edges, connected_inputs);
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn loaded_project_is_acyclic() {
+    let project = common::load_test_project();
+    let chain = project.ctx.chain().expect("project has no chain");
+
+    assert_eq!(chain.find_cycle(), None);
+
+    // Without a cycle, the topological order has every input before its consumer
+    let sorted = chain.operators_topological().unwrap();
+    let position = |name: &str| sorted.iter().position(|op| op.name() == name);
+    for op in &sorted {
+        for (_, source) in op.input_iter() {
+            assert!(position(&source.name()) < position(&op.name()));
+        }
+    }
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn input_defaults_are_reported_per_slot() {
+    let project = common::load_test_project();
+    let chain = project.ctx.chain().expect("project has no chain");
+
+    for op in chain.operators() {
+        for binding in op.inputs() {
+            assert_eq!(binding.default, op.input_default_behavior(binding.index));
+        }
+        assert_eq!(op.input_default_behavior(op.input_count()), vivid::InputDefault::None);
+    }
+}
+
+#[cfg(feature = "petgraph")]
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn petgraph_matches_the_chain() {
+    let project = common::load_test_project();
+    let chain = project.ctx.chain().expect("project has no chain");
+
+    let graph = chain.to_petgraph();
+    let connected_inputs: usize = chain.operators().map(|op| op.input_iter().count()).sum();
+
+    assert_eq!(graph.node_count(), chain.operator_count());
+    assert_eq!(graph.edge_count(), connected_inputs);
+    assert!(!petgraph::algo::is_cyclic_directed(&graph));
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn iteration_survives_param_edits_but_stops_on_restructure() {
+    let project = common::load_test_project();
+    let chain = project.ctx.chain().expect("project has no chain");
+    assert!(chain.operator_count() >= 2, "test project needs two operators");
+
+    // Parameter edits don't change the structure, so every operator is visited
+    let mut visited = 0;
+    for mut op in chain.operators() {
+        if let Some(param) = op.params().into_iter().find(|p| !p.read_only) {
+            let value = op.get_param(&param.name).unwrap();
+            op.set_param(&param.name, &value);
+        }
+        visited += 1;
+    }
+    assert_eq!(visited, chain.operator_count());
+
+    // Reordering through another handle ends the walk instead of reading stale indices
+    let mut other = chain.clone();
+    let mut iter = chain.operators();
+    let first = iter.next().unwrap();
+    other.move_operator(&first.name(), chain.operator_count() - 1).unwrap();
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
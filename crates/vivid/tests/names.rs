@@ -0,0 +1,59 @@
+//! Name conversion failures surface through `last_error()`
+
+mod common;
+
+use vivid::OperatorCost;
+
+#[test]
+fn nul_in_name_is_reported() {
+    assert_eq!(vivid::registry_estimated_cost("Noise\0"), OperatorCost::Medium);
+
+    let message = vivid::last_error().expect("no error recorded");
+    assert!(message.contains("NUL"), "unexpected error: {message}");
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn later_core_error_replaces_nul_error() {
+    let project = common::load_test_project();
+    let chain = project.ctx.chain().expect("project has no chain");
+    let mut op = chain.operators().next().expect("project has no operators");
+
+    assert_eq!(op.get_param("a\0b"), None);
+    assert!(vivid::last_error().unwrap().contains("NUL"));
+
+    // Index-based calls never convert a name, so only vivid-core can explain this
+    assert!(!op.set_param_by_index(op.param_count(), &[0.0; 4]));
+    let message = vivid::last_error().expect("no error recorded");
+    assert!(!message.contains("NUL"), "stale wrapper error: {message}");
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn nul_in_selection_keeps_the_selection() {
+    let mut project = common::load_test_project();
+    let name = project.ctx.chain().expect("project has no chain").operator_names()[0].clone();
+
+    // Selection changes are applied on the next frame
+    project.ctx.set_selection(&[&name]);
+    project.ctx.process_frame(1.0 / 60.0).unwrap();
+    project.ctx.select_operator("a\0b");
+    project.ctx.process_frame(1.0 / 60.0).unwrap();
+
+    assert_eq!(project.ctx.selection(), [name]);
+    assert!(vivid::last_error().unwrap().contains("NUL"));
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn nul_in_result_apis_names_the_argument() {
+    let project = common::load_test_project();
+    let mut chain = project.ctx.chain().expect("project has no chain");
+    let mut op = chain.operators().next().expect("project has no operators");
+
+    let rename = chain.rename_operator("a\0b", "renamed");
+    assert!(matches!(rename, Err(vivid::Error::InvalidArgument(m)) if m.contains("NUL")));
+
+    let expr = op.set_param_expression("a\0b", "1");
+    assert!(matches!(expr, Err(vivid::Error::InvalidArgument(m)) if m.contains("NUL")));
+}
@@ -0,0 +1,52 @@
+//! Output readback against a real vivid-core
+//!
+//! ```text
+//! VIVID_TEST_PROJECT=/path/to/project cargo test -p vivid -- --ignored
+//! ```
+
+mod common;
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn output_pixels_are_tightly_packed_rgba8() {
+    let mut project = common::load_test_project();
+    project.ctx.process_frame(1.0 / 60.0).unwrap();
+
+    let (width, height, pixels) = project.ctx.read_output_pixels().unwrap();
+    assert!(width > 0 && height > 0);
+    assert_eq!(pixels.len(), width as usize * height as usize * 4);
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn snapshot_as_jpeg_writes_a_jpeg() {
+    let mut project = common::load_test_project();
+    project.ctx.process_frame(1.0 / 60.0).unwrap();
+
+    let path = std::env::temp_dir().join("vivid_snapshot_as.jpg");
+    project
+        .ctx
+        .capture_snapshot_as(&path, vivid::ImageFormat::Jpeg { quality: 0 })
+        .unwrap();
+
+    let bytes = std::fs::read(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(&bytes[..3], &[0xFF, 0xD8, 0xFF]);
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn output_pixels_into_reuses_the_buffer() {
+    let mut project = common::load_test_project();
+    project.ctx.process_frame(1.0 / 60.0).unwrap();
+
+    // Oversized buffers are trimmed to the exact size without reallocating
+    let mut buf = vec![0xAB; 16 * 1024 * 1024];
+    let capacity = buf.capacity();
+    let (width, height) = project.ctx.read_output_pixels_into(&mut buf).unwrap();
+    assert_eq!(buf.len(), width as usize * height as usize * 4);
+    assert_eq!(buf.capacity(), capacity);
+
+    let (_, _, pixels) = project.ctx.read_output_pixels().unwrap();
+    assert_eq!(buf, pixels);
+}
@@ -0,0 +1,62 @@
+//! Fixed-timestep rendering against a real vivid-core
+//!
+//! ```text
+//! VIVID_TEST_PROJECT=/path/to/project cargo test -p vivid -- --ignored
+//! ```
+
+mod common;
+
+use vivid::Error;
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn render_sequence_steps_the_clock_by_dt() {
+    let mut project = common::load_test_project();
+    project.ctx.reset_time();
+
+    let mut seen = Vec::new();
+    project
+        .ctx
+        .render_sequence(3, 0.5, |ctx, frame| {
+            seen.push((frame, ctx.time()));
+            Ok(())
+        })
+        .unwrap();
+
+    let frames: Vec<u64> = seen.iter().map(|(frame, _)| *frame).collect();
+    assert_eq!(frames, [0, 1, 2]);
+    assert!((project.ctx.time() - 1.5).abs() < 1e-9);
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn render_sequence_stops_on_callback_error() {
+    let mut project = common::load_test_project();
+
+    let mut calls = 0;
+    let result = project.ctx.render_sequence(10, 1.0 / 60.0, |_, frame| {
+        calls += 1;
+        if frame == 1 {
+            Err(Error::Internal("stop".into()))
+        } else {
+            Ok(())
+        }
+    });
+
+    assert!(matches!(result, Err(Error::Internal(_))));
+    assert_eq!(calls, 2);
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn capture_loop_writes_a_gif() {
+    let mut project = common::load_test_project();
+    let path = std::env::temp_dir().join("vivid-capture-loop-test.gif");
+    let format = vivid::LoopFormat::Gif { palette_size: 64 };
+
+    project.ctx.capture_loop(&path, 4, 1.0 / 30.0, format, |_, _| true).unwrap();
+
+    let bytes = std::fs::read(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert!(bytes.starts_with(b"GIF89a"));
+}
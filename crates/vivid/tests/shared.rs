@@ -0,0 +1,11 @@
+//! Thread-safety guarantees of `SharedContext`
+
+use vivid::SharedContext;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn shared_context_is_send_and_sync() {
+    assert_send_sync::<SharedContext>();
+    assert_send_sync::<std::sync::Arc<SharedContext>>();
+}
@@ -0,0 +1,52 @@
+//! Parameter snapshot diffs and undo patches
+
+mod common;
+
+use std::collections::BTreeMap;
+
+use vivid::ParamSnapshot;
+
+fn snapshot(entries: &[(&str, &str, f32)]) -> ParamSnapshot {
+    let mut values: BTreeMap<String, BTreeMap<String, [f32; 4]>> = BTreeMap::new();
+    for &(op, param, value) in entries {
+        values.entry(op.into()).or_default().insert(param.into(), [value, 0.0, 0.0, 0.0]);
+    }
+    ParamSnapshot { values }
+}
+
+#[test]
+fn diff_records_only_changed_params() {
+    let before = snapshot(&[("blur", "radius", 1.0), ("blur", "mix", 0.5), ("noise", "gain", 2.0)]);
+    let after = snapshot(&[("blur", "radius", 4.0), ("blur", "mix", 0.5), ("level", "gain", 1.0)]);
+
+    let patch = before.diff(&after);
+    assert_eq!(patch.len(), 1);
+    let change = &patch.changes[0];
+    assert_eq!((change.operator.as_str(), change.param.as_str()), ("blur", "radius"));
+    assert_eq!((change.before[0], change.after[0]), (1.0, 4.0));
+
+    assert_eq!(patch.inverse().inverse(), patch);
+    assert_eq!(patch.inverse(), after.diff(&before));
+    assert!(before.diff(&before).is_empty());
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn apply_patch_round_trips() {
+    let project = common::load_test_project();
+    let mut chain = project.ctx.chain().expect("project has no chain");
+
+    let (mut op, param) = common::writable_float_param(&chain);
+
+    let before = chain.param_snapshot();
+    let new_value = common::toggled_float(&op, &param);
+    assert!(op.set_param(&param.name, &[new_value, 0.0, 0.0, 0.0]));
+    let after = chain.param_snapshot();
+
+    let patch = before.diff(&after);
+    assert_eq!(patch.len(), 1);
+    assert_eq!(chain.apply_patch(&patch.inverse()), 1);
+    assert_eq!(chain.param_snapshot(), before);
+    assert_eq!(chain.apply_patch(&patch), 1);
+    assert_eq!(chain.param_snapshot(), after);
+}
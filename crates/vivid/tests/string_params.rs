@@ -0,0 +1,46 @@
+//! String parameter round-trip against a real vivid-core
+//!
+//! Needs a GPU and a project containing a `String` or `FilePath` parameter:
+//!
+//! ```text
+//! VIVID_TEST_PROJECT=/path/to/project cargo test -p vivid -- --ignored
+//! ```
+
+mod common;
+
+use vivid::ParamType;
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn string_param_round_trip() {
+    let project = common::load_test_project();
+
+    let chain = project.ctx.chain().expect("project has no chain");
+    let (mut op, param) = common::find_param(&chain, "string", |p| {
+        matches!(p.param_type, ParamType::String | ParamType::FilePath)
+    });
+
+    assert!(op.set_param_string(&param.name, "round-trip.png"));
+    assert_eq!(op.get_param_string(&param.name).as_deref(), Some("round-trip.png"));
+
+    // Unknown and non-string parameters report None
+    assert_eq!(op.get_param_string("no-such-param"), None);
+    let (float_op, float_param) =
+        common::find_param(&chain, "Float", |p| p.param_type == ParamType::Float);
+    assert_eq!(float_op.get_param_string(&float_param.name), None);
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn string_param_resets_to_saved_value() {
+    let project = common::load_test_project();
+    let chain = project.ctx.chain().expect("project has no chain");
+    let (mut op, param) = common::find_param(&chain, "string", |p| {
+        matches!(p.param_type, ParamType::String | ParamType::FilePath)
+    });
+
+    let saved = op.saved_param_string(&param.name).expect("no saved value");
+    assert!(op.set_param_string(&param.name, "edited.png"));
+    assert!(op.reset_param_to_saved(&param.name));
+    assert_eq!(op.get_param_string(&param.name), Some(saved));
+}
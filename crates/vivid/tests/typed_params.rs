@@ -0,0 +1,235 @@
+//! Typed parameter getters against a real vivid-core
+//!
+//! Each GPU test uses the first parameter of its type in the test project and
+//! fails if there is none, so the project needs one of every type tested:
+//!
+//! ```text
+//! VIVID_TEST_PROJECT=/path/to/project cargo test -p vivid -- --ignored
+//! ```
+
+mod common;
+
+use vivid::{Adsr, Color, Operator, ParamType};
+
+/// Set the first parameter of type `ty` to `value`, then run `check` on it
+fn with_param_of_type(ty: ParamType, value: [f32; 4], check: impl Fn(&Operator, &str)) {
+    let project = common::load_test_project();
+    let chain = project.ctx.chain().expect("project has no chain");
+
+    let (mut op, param) = common::find_param(&chain, &format!("{ty:?}"), |p| p.param_type == ty);
+
+    assert!(op.set_param(&param.name, &value));
+    check(&op, &param.name);
+
+    // Unknown parameters report None
+    assert_eq!(op.get_param_f32("no-such-param"), None);
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn float_param() {
+    with_param_of_type(ParamType::Float, [0.25, 0.0, 0.0, 0.0], |op, name| {
+        assert_eq!(op.get_param_f32(name), Some(0.25));
+        assert_eq!(op.get_param_bool(name), None);
+    });
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn int_param() {
+    with_param_of_type(ParamType::Int, [3.0, 0.0, 0.0, 0.0], |op, name| {
+        assert_eq!(op.get_param_i32(name), Some(3));
+        assert_eq!(op.get_param_f32(name), None);
+    });
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn bool_param() {
+    with_param_of_type(ParamType::Bool, [1.0, 0.0, 0.0, 0.0], |op, name| {
+        assert_eq!(op.get_param_bool(name), Some(true));
+    });
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn vec2_param() {
+    with_param_of_type(ParamType::Vec2, [0.5, 0.25, 0.0, 0.0], |op, name| {
+        assert_eq!(op.get_param_vec2(name), Some((0.5, 0.25)));
+    });
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn vec3_param() {
+    with_param_of_type(ParamType::Vec3, [0.5, 0.25, 0.125, 0.0], |op, name| {
+        assert_eq!(op.get_param_vec3(name), Some((0.5, 0.25, 0.125)));
+    });
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn vec4_param() {
+    with_param_of_type(ParamType::Vec4, [0.5, 0.25, 0.125, 1.0], |op, name| {
+        assert_eq!(op.get_param_vec4(name), Some((0.5, 0.25, 0.125, 1.0)));
+    });
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn adsr_param() {
+    let adsr = Adsr {
+        attack: 0.01,
+        decay: 0.2,
+        sustain: 0.5,
+        release: 1.5,
+    };
+    with_param_of_type(ParamType::Adsr, adsr.into(), |op, name| {
+        assert_eq!(op.get_param_adsr(name), Some(adsr));
+        assert_eq!(op.get_param(name), Some([0.01, 0.2, 0.5, 1.5]));
+    });
+}
+
+#[test]
+fn adsr_packing_round_trip() {
+    let packed = [0.01, 0.2, 0.5, 1.5];
+    let adsr = Adsr::from(packed);
+    assert_eq!(adsr.attack, 0.01);
+    assert_eq!(adsr.decay, 0.2);
+    assert_eq!(adsr.sustain, 0.5);
+    assert_eq!(adsr.release, 1.5);
+    assert_eq!(<[f32; 4]>::from(adsr), packed);
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn color_param() {
+    with_param_of_type(ParamType::Color, [1.0, 0.5, 0.0, 1.0], |op, name| {
+        let color = op.get_param_color(name).unwrap();
+        assert_eq!(color.to_hex(), "#ff8000");
+        assert_eq!(op.get_param_vec4(name), Some((1.0, 0.5, 0.0, 1.0)));
+    });
+}
+
+#[test]
+fn color_hex_round_trip() {
+    let color = Color::from_hex("#FF8000").unwrap();
+    assert_eq!(color, Color { r: 1.0, g: 128.0 / 255.0, b: 0.0, a: 1.0 });
+    assert_eq!(color.to_hex(), "#ff8000");
+
+    let translucent = Color::from_hex("00ff0080").unwrap();
+    assert_eq!(translucent.to_hex(), "#00ff0080");
+
+    assert_eq!(Color::from_hex("#fff"), None);
+    assert_eq!(Color::from_hex("#gg0000"), None);
+    assert_eq!(Color::from_hex("#+f+f+f"), None);
+    assert_eq!(Color::from_hex("#ff00ff-1"), None);
+
+    // Every byte value survives a round trip
+    for v in 0..=255u8 {
+        let hex = format!("#{v:02x}{v:02x}{v:02x}{v:02x}");
+        let expected = if v == 255 { &hex[..7] } else { &hex[..] };
+        assert_eq!(Color::from_hex(&hex).unwrap().to_hex(), expected);
+    }
+}
+
+#[test]
+fn color_linear_srgb_round_trip() {
+    let color = Color { r: 0.5, g: 0.02, b: 1.0, a: 0.25 };
+    let linear = color.to_linear();
+    assert!((linear.r - 0.214).abs() < 1e-3);
+    assert_eq!(linear.a, 0.25);
+
+    let back = linear.to_srgb();
+    for (a, b) in [(back.r, color.r), (back.g, color.g), (back.b, color.b)] {
+        assert!((a - b).abs() < 1e-5);
+    }
+
+    // Black and white are fixed points of both transfer functions
+    for v in [0.0, 1.0] {
+        let c = Color { r: v, g: v, b: v, a: 1.0 };
+        for converted in [c.to_linear(), c.to_srgb()] {
+            assert!((converted.r - v).abs() < 1e-6, "{v} -> {}", converted.r);
+        }
+    }
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn param_generation_moves_on_change() {
+    let project = common::load_test_project();
+    let chain = project.ctx.chain().expect("project has no chain");
+
+    let (mut op, param) = common::writable_float_param(&chain);
+
+    let before = op.param_generation(&param.name).unwrap();
+    let new_value = common::toggled_float(&op, &param);
+    assert!(op.set_param(&param.name, &[new_value, 0.0, 0.0, 0.0]));
+    assert!(op.param_generation(&param.name).unwrap() > before);
+
+    assert_eq!(op.param_generation("no-such-param"), None);
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn apply_params_reports_mismatches() {
+    let project = common::load_test_project();
+    let chain = project.ctx.chain().expect("project has no chain");
+    let mut op = chain.operators().next().expect("project has no operators");
+
+    // Re-applying the current values succeeds, except for read-only params
+    let mut preset = op.param_values();
+    let read_only: Vec<String> = op
+        .params()
+        .into_iter()
+        .filter(|p| p.read_only && preset.contains_key(&p.name))
+        .map(|p| p.name)
+        .collect();
+    preset.insert("no-such-param".into(), [1.0, 0.0, 0.0, 0.0]);
+
+    let mut expected = read_only;
+    expected.push("no-such-param".into());
+    expected.sort();
+    assert_eq!(op.apply_params(&preset), expected);
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn param_values_round_trip_alongside_a_file_param() {
+    let project = common::load_test_project();
+    let chain = project.ctx.chain().expect("project has no chain");
+    let (mut op, file_param) =
+        common::find_param(&chain, "FilePath", |p| p.param_type == ParamType::FilePath);
+
+    let preset = op.param_values();
+    assert!(!preset.contains_key(&file_param.name));
+
+    let read_only: Vec<String> = op
+        .params()
+        .into_iter()
+        .filter(|p| p.read_only && preset.contains_key(&p.name))
+        .map(|p| p.name)
+        .collect();
+    let mut failed = op.apply_params(&preset);
+    failed.retain(|name| !read_only.contains(name));
+    assert!(failed.is_empty(), "unexpected failures: {failed:?}");
+    assert_eq!(op.param_values(), preset);
+}
+
+#[test]
+#[ignore = "requires a GPU and VIVID_TEST_PROJECT"]
+fn reset_all_params_restores_declared_defaults() {
+    let project = common::load_test_project();
+    let chain = project.ctx.chain().expect("project has no chain");
+    let mut op = chain.operators().next().expect("project has no operators");
+
+    assert!(op.reset_all_params().is_empty());
+    for (index, decl) in op.params().into_iter().enumerate() {
+        if decl.read_only || matches!(decl.param_type, ParamType::String | ParamType::FilePath) {
+            continue;
+        }
+        assert_eq!(op.get_param_by_index(index), Some(decl.default_val), "{}", decl.name);
+    }
+
+    assert!(!op.reset_param("no-such-param"));
+}
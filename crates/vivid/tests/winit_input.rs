@@ -0,0 +1,61 @@
+//! winit event translation
+//!
+//! ```text
+//! cargo test -p vivid --features winit
+//! ```
+
+#![cfg(feature = "winit")]
+
+use vivid::InputFrame;
+use winit::dpi::PhysicalPosition;
+use winit::event::{DeviceId, ElementState, MouseButton, MouseScrollDelta, TouchPhase, WindowEvent};
+
+fn device() -> DeviceId {
+    DeviceId::dummy()
+}
+
+#[test]
+fn buttons_use_glfw_numbering() {
+    let mut frame = InputFrame::new();
+    for button in [MouseButton::Left, MouseButton::Right, MouseButton::Middle] {
+        frame.from_winit_event(&WindowEvent::MouseInput {
+            device_id: device(),
+            state: ElementState::Pressed,
+            button,
+        });
+    }
+    assert_eq!(frame.mouse_buttons, [(0, true), (1, true), (2, true)]);
+}
+
+#[test]
+fn cursor_and_scroll_are_accumulated() {
+    let mut frame = InputFrame::new();
+    frame
+        .from_winit_event(&WindowEvent::CursorMoved {
+            device_id: device(),
+            position: PhysicalPosition::new(10.0, 20.0),
+        })
+        .from_winit_event(&WindowEvent::MouseWheel {
+            device_id: device(),
+            delta: MouseScrollDelta::LineDelta(0.0, 1.0),
+            phase: TouchPhase::Moved,
+        })
+        .from_winit_event(&WindowEvent::MouseWheel {
+            device_id: device(),
+            delta: MouseScrollDelta::PixelDelta(PhysicalPosition::new(
+                0.0,
+                vivid::PIXELS_PER_SCROLL_LINE,
+            )),
+            phase: TouchPhase::Moved,
+        });
+
+    assert_eq!(frame.mouse_position, Some((10.0, 20.0)));
+    assert_eq!(frame.scroll, (0.0, 2.0));
+}
+
+#[test]
+fn unrelated_events_are_ignored() {
+    let mut frame = InputFrame::new();
+    frame.from_winit_event(&WindowEvent::Focused(true));
+    assert!(frame.is_empty());
+}
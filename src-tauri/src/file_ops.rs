@@ -1,5 +1,8 @@
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Top-level directories skipped when duplicating a project (build artifacts and VCS data)
+const DUPLICATE_EXCLUDED_DIRS: &[&str] = &["build", ".git"];
 
 #[tauri::command]
 pub fn get_home_dir() -> Result<String, String> {
@@ -112,3 +115,79 @@ pub async fn create_project(path: String, name: String, template: Option<String>
 
     Ok(())
 }
+
+#[tauri::command]
+pub async fn duplicate_project(src: String, dst: String) -> Result<(), String> {
+    let src_path = fs::canonicalize(&src)
+        .ok()
+        .filter(|p| p.is_dir())
+        .ok_or_else(|| format!("Project directory not found: {}", src))?;
+
+    let dst_path = PathBuf::from(&dst);
+    if dst_path.exists() {
+        return Err(format!("Destination already exists: {}", dst));
+    }
+
+    // Compare resolved paths so relative and symlinked destinations inside the
+    // project are caught too (copying into itself would never terminate)
+    let dst_name = dst_path
+        .file_name()
+        .ok_or_else(|| format!("Invalid destination: {}", dst))?;
+    let dst_parent = match dst_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let dst_path = fs::canonicalize(dst_parent)
+        .map_err(|e| format!("Invalid destination directory {:?}: {}", dst_parent, e))?
+        .join(dst_name);
+    if dst_path.starts_with(&src_path) {
+        return Err("Cannot duplicate a project into itself".to_string());
+    }
+
+    copy_dir_recursive(&src_path, &dst_path, DUPLICATE_EXCLUDED_DIRS)
+        .map_err(|e| format!("Failed to duplicate project: {}", e))
+}
+
+/// Recursively copy a directory, skipping the `excluded` names at this level only
+///
+/// Symlinks are recreated as links rather than followed.
+fn copy_dir_recursive(src: &Path, dst: &Path, excluded: &[&str]) -> std::io::Result<()> {
+    fs::create_dir_all(dst)?;
+
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = dst.join(entry.file_name());
+
+        if file_type.is_symlink() {
+            copy_symlink(&entry.path(), &target)?;
+        } else if file_type.is_dir() {
+            let name = entry.file_name();
+            if excluded.iter().any(|d| name == *d) {
+                continue;
+            }
+            copy_dir_recursive(&entry.path(), &target, &[])?;
+        } else if file_type.is_file() {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Recreate the symlink at `src` as `dst`, pointing at the same target
+#[cfg(unix)]
+fn copy_symlink(src: &Path, dst: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(fs::read_link(src)?, dst)
+}
+
+/// Recreate the symlink at `src` as `dst`, pointing at the same target
+#[cfg(windows)]
+fn copy_symlink(src: &Path, dst: &Path) -> std::io::Result<()> {
+    let link = fs::read_link(src)?;
+    if fs::metadata(src).map(|m| m.is_dir()).unwrap_or(false) {
+        std::os::windows::fs::symlink_dir(link, dst)
+    } else {
+        std::os::windows::fs::symlink_file(link, dst)
+    }
+}
@@ -4,10 +4,12 @@
 mod file_ops;
 mod output_capture;
 mod pty;
+mod recent_projects;
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::panic::AssertUnwindSafe;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, MutexGuard, TryLockError};
 use std::time::Instant;
 use tauri::{AppHandle, Manager, RunEvent, WindowEvent, Emitter};
 use tauri::menu::{Menu, MenuBuilder, MenuItemBuilder, SubmenuBuilder, PredefinedMenuItem};
@@ -20,10 +22,33 @@ use serde::{Deserialize, Serialize};
 /// Wrapper around vivid::Context for thread-safe access
 struct VividContext {
     ctx: vivid::Context,
+    /// Operator handles by name, so repeated edits (e.g. a dragged slider) skip the
+    /// lookup in the core. Handles point into the chain, so this must be cleared
+    /// whenever the chain is rebuilt.
+    operator_cache: HashMap<String, vivid::Operator>,
+    /// Set by the compile callback when the core rebuilds the chain on its own
+    /// (hot reload), since the callback runs while the context is locked
+    operator_cache_stale: Arc<AtomicBool>,
 }
 
-// Safety: vivid::Context contains raw pointers but is single-threaded.
-// We only access it from the main thread via Mutex.
+impl VividContext {
+    /// Get a cached operator handle, looking it up on first use
+    fn operator_mut(&mut self, name: &str) -> Option<&mut vivid::Operator> {
+        if self.operator_cache_stale.swap(false, Ordering::SeqCst) {
+            self.operator_cache.clear();
+        }
+        if !self.operator_cache.contains_key(name) {
+            let op = self.ctx.chain()?.operator_by_name(name)?;
+            self.operator_cache.insert(name.to_string(), op);
+        }
+        self.operator_cache.get_mut(name)
+    }
+}
+
+// Safety: vivid::Context contains raw pointers but is single-threaded. Every
+// access goes through the Mutex, and only sync commands (which Tauri runs on
+// the main thread) and the main-thread render loop take it; async commands must
+// not lock it.
 unsafe impl Send for VividContext {}
 unsafe impl Sync for VividContext {}
 
@@ -39,10 +64,17 @@ pub struct AppState {
     start_time: Mutex<Option<Instant>>,
     /// Flag to signal render thread to stop
     render_running: AtomicBool,
+    /// Set when render_frame panicked or the GPU device was lost; rendering stays
+    /// paused until the next successful reload or reinitialization
+    render_crashed: AtomicBool,
     /// Frame counter for render timing - incremented by timer thread, decremented after render
     render_pending: AtomicU64,
+    /// Input forwarded from the webview since the last render, flushed once per frame
+    pending_input: Mutex<vivid::InputFrame>,
     /// Performance stats tracking
     perf_stats: Mutex<PerformanceStats>,
+    /// Which optional metrics to gather; off until the performance panel asks for them
+    perf_metrics: Mutex<PerfMetricFlags>,
     /// Last frame time for FPS calculation
     last_frame_time: Mutex<Option<Instant>>,
     /// Frame count since last FPS update
@@ -65,8 +97,11 @@ impl Default for AppState {
             init_attempted: AtomicBool::new(false),
             start_time: Mutex::new(None),
             render_running: AtomicBool::new(false),
+            render_crashed: AtomicBool::new(false),
             render_pending: AtomicU64::new(0),
+            pending_input: Mutex::new(vivid::InputFrame::new()),
             perf_stats: Mutex::new(PerformanceStats::default()),
+            perf_metrics: Mutex::new(PerfMetricFlags::default()),
             last_frame_time: Mutex::new(None),
             fps_frame_count: AtomicU64::new(0),
             last_fps_time: Mutex::new(None),
@@ -78,9 +113,45 @@ impl Default for AppState {
 }
 
 impl AppState {
+    /// Lock the vivid context, recovering from a poisoned mutex
+    ///
+    /// A panic while the lock is held (e.g. on the render path) poisons the mutex.
+    /// Instead of every later access looking like "vivid not initialized", the
+    /// guard is recovered and the frontend is told the renderer crashed.
+    fn lock_vivid(&self) -> MutexGuard<'_, Option<VividContext>> {
+        self.vivid.lock().unwrap_or_else(|poisoned| {
+            self.report_poisoned();
+            poisoned.into_inner()
+        })
+    }
+
+    /// Try to lock the vivid context without blocking, recovering from poison
+    ///
+    /// Returns `None` only if the lock is currently held elsewhere.
+    fn try_lock_vivid(&self) -> Option<MutexGuard<'_, Option<VividContext>>> {
+        match self.vivid.try_lock() {
+            Ok(guard) => Some(guard),
+            Err(TryLockError::Poisoned(poisoned)) => {
+                self.report_poisoned();
+                Some(poisoned.into_inner())
+            }
+            Err(TryLockError::WouldBlock) => None,
+        }
+    }
+
+    /// Log and emit a crash event for a poisoned vivid mutex, then clear the poison
+    /// so the crash is only reported once
+    fn report_poisoned(&self) {
+        log::error!("Vivid context mutex was poisoned by a panic; recovering");
+        self.vivid.clear_poison();
+        self.emit("vivid-render-crashed", RendererCrashedPayload {
+            message: "The renderer panicked while holding the vivid context".into(),
+        });
+    }
+
     /// Check if vivid is initialized
     fn is_initialized(&self) -> bool {
-        self.vivid.lock().map(|g| g.is_some()).unwrap_or(false)
+        self.lock_vivid().is_some()
     }
 
     /// Execute a function with read-only vivid context access
@@ -88,7 +159,7 @@ impl AppState {
     where
         F: FnOnce(&vivid::Context) -> T,
     {
-        let guard = self.vivid.lock().ok()?;
+        let guard = self.lock_vivid();
         guard.as_ref().map(|v| f(&v.ctx))
     }
 
@@ -97,7 +168,7 @@ impl AppState {
     where
         F: FnOnce(&vivid::Context) -> T,
     {
-        let guard = self.vivid.try_lock().ok()?;
+        let guard = self.try_lock_vivid()?;
         guard.as_ref().map(|v| f(&v.ctx))
     }
 
@@ -106,10 +177,68 @@ impl AppState {
     where
         F: FnOnce(&mut vivid::Context) -> T,
     {
-        let mut guard = self.vivid.lock().ok()?;
+        let mut guard = self.lock_vivid();
         guard.as_mut().map(|v| f(&mut v.ctx))
     }
 
+    /// Drop cached operator handles (call after the chain is reloaded or replaced)
+    fn invalidate_operator_cache(&self) {
+        if let Some(vivid) = self.lock_vivid().as_mut() {
+            vivid.operator_cache.clear();
+        }
+    }
+
+    /// Render a frame, catching panics from the render path
+    ///
+    /// Flushes pending input first. Uses try_lock to avoid blocking during
+    /// project loading; input stays queued until the lock is free. A panic pauses
+    /// rendering and emits `vivid-render-crashed` instead of taking down the app,
+    /// so the user can still save their work and reload.
+    fn render_frame(&self) {
+        if self.render_crashed.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let Some(mut guard) = self.try_lock_vivid() else {
+            return;
+        };
+        let Some(ref mut vivid_ctx) = *guard else {
+            return;
+        };
+
+        // Flush input accumulated since the last frame in one call
+        if let Ok(mut pending) = self.pending_input.lock() {
+            if !pending.is_empty() {
+                vivid_ctx.ctx.apply_input_frame(&pending);
+                pending.clear();
+            }
+        }
+
+        match std::panic::catch_unwind(AssertUnwindSafe(|| vivid_ctx.ctx.render_frame())) {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                log::error!("Render error: {:?}", e);
+                if vivid_ctx.ctx.is_device_lost() {
+                    log::error!("GPU device lost; pausing rendering until reinitialized");
+                    self.render_crashed.store(true, Ordering::SeqCst);
+                    self.emit("vivid-device-lost", ());
+                }
+            }
+            Err(panic) => {
+                let reason = panic
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| panic.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic".to_string());
+                log::error!("Render frame panicked: {}", reason);
+                self.render_crashed.store(true, Ordering::SeqCst);
+                self.emit("vivid-render-crashed", RendererCrashedPayload {
+                    message: format!("Render frame panicked: {}", reason),
+                });
+            }
+        }
+    }
+
     /// Emit an event to the frontend
     fn emit<S: Serialize + Clone>(&self, event: &str, payload: S) {
         if let Ok(guard) = self.app_handle.lock() {
@@ -119,6 +248,22 @@ impl AppState {
         }
     }
 
+    /// Emit `vivid-chain-changed` with the current chain version
+    ///
+    /// Every command that changes the chain structure goes through here, so views
+    /// only need the one subscription to stay in sync. Must not be called while
+    /// holding the vivid lock.
+    fn emit_chain_changed(&self, reason: &str) {
+        let version = self
+            .with_vivid(|ctx| ctx.chain().map(|chain| chain.version()))
+            .flatten()
+            .unwrap_or(0);
+        self.emit(
+            "vivid-chain-changed",
+            ChainChangedPayload { reason: reason.to_string(), version },
+        );
+    }
+
     /// Update performance stats after each frame
     fn update_performance_stats(&self) {
         let now = Instant::now();
@@ -175,8 +320,15 @@ impl AppState {
                     }
                 }
 
+                let metrics = self.perf_metrics.lock()
+                    .map(|m| *m)
+                    .unwrap_or_default();
+
                 // Update memory history (get process memory)
-                if let Ok(mut history) = self.memory_history.lock() {
+                // Skipped unless the performance panel requested it
+                if !metrics.memory {
+                    // Nothing to sample
+                } else if let Ok(mut history) = self.memory_history.lock() {
                     let memory_mb = get_process_memory_mb();
                     history.push_back(memory_mb);
                     while history.len() > HISTORY_SIZE {
@@ -200,20 +352,32 @@ impl AppState {
                     }
 
                     // Get operator count and texture memory estimate
-                    if let Some((op_count, tex_mem)) = self.try_with_vivid(|ctx| {
-                        if let Some(chain) = ctx.chain() {
-                            let ops: Vec<_> = chain.operators().collect();
-                            let texture_ops = ops.iter().filter(|op| {
-                                format!("{:?}", op.output_kind()) == "Texture"
-                            }).count();
-                            let tex_mem = texture_ops as u64 * ctx.width() as u64 * ctx.height() as u64 * 4;
-                            (ops.len(), tex_mem)
-                        } else {
-                            (0, 0)
+                    let chain_stats = if metrics.gpu || metrics.operators {
+                        self.try_with_vivid(|ctx| {
+                            if let Some(chain) = ctx.chain() {
+                                let texture_ops = chain.operators_by_output_kind(vivid::OutputKind::Texture).len();
+                                let tex_mem = texture_ops as u64 * ctx.width() as u64 * ctx.height() as u64 * 4;
+                                (chain.operator_count(), tex_mem)
+                            } else {
+                                (0, 0)
+                            }
+                        })
+                    } else {
+                        None
+                    };
+                    if let Some((op_count, tex_mem)) = chain_stats {
+                        if metrics.operators {
+                            stats.operator_count = op_count;
                         }
-                    }) {
-                        stats.operator_count = op_count;
-                        stats.texture_memory_bytes = tex_mem;
+                        if metrics.gpu {
+                            stats.texture_memory_bytes = tex_mem;
+                        }
+                    }
+
+                    if let Some(session) = self.try_with_vivid(|ctx| ctx.session_stats()) {
+                        stats.session_frames = session.total_frames;
+                        stats.session_render_seconds = session.total_render_seconds;
+                        stats.session_uptime_seconds = session.uptime_seconds;
                     }
                 }
             }
@@ -225,17 +389,29 @@ impl AppState {
 fn get_process_memory_mb() -> f64 {
     #[cfg(target_os = "macos")]
     {
-        use std::process::Command;
-        // Use ps to get RSS (resident set size) in KB
-        if let Ok(output) = Command::new("ps")
-            .args(["-o", "rss=", "-p", &std::process::id().to_string()])
-            .output()
-        {
-            if let Ok(s) = String::from_utf8(output.stdout) {
-                if let Ok(kb) = s.trim().parse::<f64>() {
-                    return kb / 1024.0; // Convert KB to MB
-                }
-            }
+        use mach2::kern_return::KERN_SUCCESS;
+        use mach2::message::mach_msg_type_number_t;
+        use mach2::task::task_info;
+        use mach2::task_info::{
+            mach_task_basic_info, task_info_t, MACH_TASK_BASIC_INFO, MACH_TASK_BASIC_INFO_COUNT,
+        };
+        use mach2::traps::mach_task_self;
+
+        // Read RSS (resident set size) straight from the kernel instead of spawning `ps`.
+        // MACH_TASK_BASIC_INFO replaces the legacy TASK_BASIC_INFO, whose 32-bit-era
+        // fields can disagree with Activity Monitor
+        let mut info = mach_task_basic_info::default();
+        let mut count: mach_msg_type_number_t = MACH_TASK_BASIC_INFO_COUNT;
+        let result = unsafe {
+            task_info(
+                mach_task_self(),
+                MACH_TASK_BASIC_INFO,
+                &mut info as *mut mach_task_basic_info as task_info_t,
+                &mut count,
+            )
+        };
+        if result == KERN_SUCCESS {
+            return info.resident_size as f64 / (1024.0 * 1024.0); // Bytes to MB
         }
         0.0
     }
@@ -267,6 +443,10 @@ pub struct ProjectInfo {
     pub loaded: bool,
     pub project_path: Option<String>,
     pub chain_path: Option<String>,
+    /// Operators that failed to load; non-empty means the project is only partially loaded
+    pub failed_operators: Vec<String>,
+    /// False after a render crash; lets the UI tell "empty" from "broken"
+    pub renderer_healthy: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -275,14 +455,41 @@ pub struct CompileStatusInfo {
     pub message: Option<String>,
     pub error_line: Option<u32>,
     pub error_column: Option<u32>,
+    /// Editor markers to show; always empty on success
+    pub diagnostics: Vec<CompileDiagnosticInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompileDiagnosticInfo {
+    pub message: String,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+}
+
+impl From<vivid::CompileDiagnostic> for CompileDiagnosticInfo {
+    fn from(diagnostic: vivid::CompileDiagnostic) -> Self {
+        Self {
+            message: diagnostic.message,
+            line: diagnostic.line,
+            column: diagnostic.column,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OperatorInfo {
     pub name: String,
     pub type_name: String,
+    pub category: String,
+    pub help: Option<String>,
     pub output_kind: String,
     pub bypassed: bool,
+    /// Changes whenever `bypassed` is toggled, so views can detect external toggles
+    pub bypass_version: u64,
+    /// Position in the scheduler's per-frame run order, `None` if not scheduled
+    pub execution_order: Option<u32>,
+    /// Runtime error that isolated this operator (output black), if failing
+    pub runtime_error: Option<String>,
     pub input_count: usize,
     pub inputs: Vec<String>,
 }
@@ -296,6 +503,56 @@ pub struct ParamInfo {
     pub value: [f32; 4],
     pub default_val: [f32; 4],
     pub enum_labels: Vec<String>,
+    pub enum_values: Vec<i32>,
+    pub group: Option<String>,
+    pub read_only: bool,
+    pub help: Option<String>,
+    /// Display unit (e.g. "Hz", "px"), if any
+    pub unit: Option<String>,
+    /// Expression driving the value each frame, if any
+    pub expression: Option<String>,
+    /// What drives the value: "static", "expression", "midi", "automated" or "osc"
+    pub binding: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetRefInfo {
+    pub path: String,
+    /// Absolute path after resolving against the project root
+    pub resolved: String,
+    /// Asset category ("Image", "Video", "Audio", ...)
+    pub kind: String,
+    pub exists: bool,
+    pub referenced_by: String,
+    pub param: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputInfo {
+    pub width: u32,
+    pub height: u32,
+    /// Raw WGPUTextureFormat value
+    pub format: i32,
+    /// Short format name (e.g. "RGBA16F"), if known
+    pub format_name: Option<String>,
+    pub has_alpha: bool,
+    /// Output color space ("Srgb", "LinearSrgb", "DisplayP3", "Unknown")
+    pub color_space: String,
+    /// How alpha is encoded ("Opaque", "Premultiplied", "Straight")
+    pub alpha_mode: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperatorValue {
+    pub name: String,
+    pub value: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceLocationInfo {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -307,6 +564,23 @@ pub struct PerformanceStats {
     pub memory_history: Vec<f64>,
     pub texture_memory_bytes: u64,
     pub operator_count: usize,
+    /// Frames rendered since the vivid context was created (survives time resets)
+    pub session_frames: u64,
+    pub session_render_seconds: f64,
+    pub session_uptime_seconds: f64,
+}
+
+/// Optional performance metrics the frontend wants gathered
+///
+/// FPS and frame time are always tracked; these are the costlier extras.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+pub struct PerfMetricFlags {
+    /// Process memory (resident set size)
+    pub memory: bool,
+    /// GPU texture memory estimate
+    pub gpu: bool,
+    /// Per-operator stats (operator count)
+    pub operators: bool,
 }
 
 // =============================================================================
@@ -318,6 +592,42 @@ pub struct VividInitializedPayload {
     pub success: bool,
     pub project_loaded: bool,
     pub project_path: Option<String>,
+    /// Failure detail when initialization (or the startup project load) failed
+    pub error: Option<String>,
+    pub error_kind: Option<InitErrorKind>,
+}
+
+/// Which initialization step failed, so the UI can show actionable guidance
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InitErrorKind {
+    /// The native window handle or size was unavailable
+    Window,
+    /// The vivid installation (shaders, fonts, assets) couldn't be located
+    AssetPaths,
+    /// GPU device/surface creation failed
+    ContextCreation,
+    /// The context was created but the startup project failed to load
+    ProjectLoad,
+}
+
+/// Initialization failure with the step that failed
+#[derive(Debug, Clone)]
+pub struct InitError {
+    pub kind: InitErrorKind,
+    pub message: String,
+}
+
+impl InitError {
+    fn new(kind: InitErrorKind, message: impl Into<String>) -> Self {
+        Self { kind, message: message.into() }
+    }
+}
+
+impl std::fmt::Display for InitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -326,6 +636,20 @@ pub struct CompileStatusPayload {
     pub message: Option<String>,
     pub error_line: Option<u32>,
     pub error_column: Option<u32>,
+    /// Editor markers to show; an empty list on success clears stale ones
+    pub diagnostics: Vec<CompileDiagnosticInfo>,
+}
+
+impl From<vivid::CompileStatus> for CompileStatusPayload {
+    fn from(status: vivid::CompileStatus) -> Self {
+        Self {
+            diagnostics: status.diagnostics().into_iter().map(Into::into).collect(),
+            success: status.success,
+            message: status.message,
+            error_line: status.error_line,
+            error_column: status.error_column,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -333,6 +657,30 @@ pub struct OperatorSelectedPayload {
     pub name: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct SelectionChangedPayload {
+    pub names: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainChangedPayload {
+    /// The command that changed the chain (e.g. "rename_operator")
+    pub reason: String,
+    /// Chain structure version after the change; lets listeners skip stale events
+    pub version: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RendererCrashedPayload {
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OperatorErrorPayload {
+    pub operator: String,
+    pub message: String,
+}
+
 // =============================================================================
 // Tauri commands for vivid state
 // =============================================================================
@@ -340,13 +688,20 @@ pub struct OperatorSelectedPayload {
 #[tauri::command]
 fn get_project_info(state: tauri::State<'_, Arc<AppState>>) -> ProjectInfo {
     log::info!("[Tauri] get_project_info called");
+    let renderer_healthy = !state.render_crashed.load(Ordering::SeqCst);
     state.with_vivid(|ctx| {
         let project_path = ctx.project_path();
         let chain_path = project_path.as_ref().map(|p| format!("{}/chain.cpp", p));
+        let failed_operators = match ctx.load_state() {
+            vivid::LoadState::PartiallyLoaded { failed_ops } => failed_ops,
+            vivid::LoadState::Empty | vivid::LoadState::FullyLoaded => Vec::new(),
+        };
         let info = ProjectInfo {
             loaded: ctx.has_project(),
             project_path: project_path.clone(),
             chain_path,
+            failed_operators,
+            renderer_healthy,
         };
         log::info!("[Tauri] get_project_info returning: loaded={}, path={:?}", info.loaded, project_path);
         info
@@ -356,6 +711,8 @@ fn get_project_info(state: tauri::State<'_, Arc<AppState>>) -> ProjectInfo {
             loaded: false,
             project_path: None,
             chain_path: None,
+            failed_operators: Vec::new(),
+            renderer_healthy,
         }
     })
 }
@@ -365,6 +722,7 @@ fn get_compile_status(state: tauri::State<'_, Arc<AppState>>) -> CompileStatusIn
     state.with_vivid(|ctx| {
         let status = ctx.compile_status();
         CompileStatusInfo {
+            diagnostics: status.diagnostics().into_iter().map(Into::into).collect(),
             success: status.success,
             message: status.message,
             error_line: status.error_line,
@@ -375,9 +733,17 @@ fn get_compile_status(state: tauri::State<'_, Arc<AppState>>) -> CompileStatusIn
         message: None,
         error_line: None,
         error_column: None,
+        diagnostics: Vec::new(),
     })
 }
 
+#[tauri::command]
+fn set_perf_metrics(state: tauri::State<'_, Arc<AppState>>, enabled: PerfMetricFlags) {
+    if let Ok(mut metrics) = state.perf_metrics.lock() {
+        *metrics = enabled;
+    }
+}
+
 #[tauri::command]
 fn get_performance_stats(state: tauri::State<'_, Arc<AppState>>) -> PerformanceStats {
     state.perf_stats.lock()
@@ -385,6 +751,13 @@ fn get_performance_stats(state: tauri::State<'_, Arc<AppState>>) -> PerformanceS
         .unwrap_or_default()
 }
 
+/// Recent per-frame render times (ms) from vivid-core, oldest first; a longer
+/// window than the 120-sample history in `PerformanceStats`
+#[tauri::command]
+fn get_frame_times(state: tauri::State<'_, Arc<AppState>>) -> Vec<f32> {
+    state.with_vivid(|ctx| ctx.recent_frame_times()).unwrap_or_default()
+}
+
 #[tauri::command]
 fn get_operators(state: tauri::State<'_, Arc<AppState>>) -> Vec<OperatorInfo> {
     log::info!("[Tauri] get_operators called");
@@ -392,16 +765,18 @@ fn get_operators(state: tauri::State<'_, Arc<AppState>>) -> Vec<OperatorInfo> {
         let mut ops = Vec::new();
         if let Some(chain) = ctx.chain() {
             for op in chain.operators() {
-                let mut inputs = Vec::new();
-                for i in 0..op.input_count() {
-                    inputs.push(op.input_name(i));
-                }
+                let inputs: Vec<String> = op.inputs().into_iter().map(|b| b.label).collect();
                 ops.push(OperatorInfo {
                     name: op.name(),
                     type_name: op.type_name(),
+                    category: op.category(),
+                    help: op.help_text(),
                     output_kind: format!("{:?}", op.output_kind()),
                     bypassed: op.is_bypassed(),
-                    input_count: op.input_count(),
+                    bypass_version: op.bypass_version(),
+                    execution_order: op.execution_order(),
+                    runtime_error: op.runtime_error(),
+                    input_count: inputs.len(),
                     inputs,
                 });
             }
@@ -421,6 +796,13 @@ fn get_operator_params(state: tauri::State<'_, Arc<AppState>>, op_name: String)
             if let Some(op) = chain.operator_by_name(&op_name) {
                 for decl in op.params() {
                     let value = op.get_param(&decl.name).unwrap_or([0.0; 4]);
+                    let (binding, expression) = match op.param_binding(&decl.name) {
+                        vivid::ParamBinding::Static => ("static", None),
+                        vivid::ParamBinding::Expression(expr) => ("expression", Some(expr)),
+                        vivid::ParamBinding::Midi => ("midi", None),
+                        vivid::ParamBinding::Automated => ("automated", None),
+                        vivid::ParamBinding::Osc => ("osc", None),
+                    };
                     params.push(ParamInfo {
                         name: decl.name,
                         param_type: format!("{:?}", decl.param_type),
@@ -429,6 +811,13 @@ fn get_operator_params(state: tauri::State<'_, Arc<AppState>>, op_name: String)
                         value,
                         default_val: decl.default_val,
                         enum_labels: decl.enum_labels,
+                        enum_values: decl.enum_values,
+                        group: decl.group,
+                        read_only: decl.read_only,
+                        help: decl.description,
+                        unit: decl.unit,
+                        expression,
+                        binding: binding.to_string(),
                     });
                 }
             }
@@ -437,6 +826,157 @@ fn get_operator_params(state: tauri::State<'_, Arc<AppState>>, op_name: String)
     }).unwrap_or_default()
 }
 
+#[tauri::command]
+fn get_output_values(state: tauri::State<'_, Arc<AppState>>) -> Vec<OperatorValue> {
+    state.with_vivid(|ctx| {
+        ctx.chain()
+            .map(|chain| {
+                chain.output_values()
+                    .into_iter()
+                    .map(|(name, value)| OperatorValue { name, value })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }).unwrap_or_default()
+}
+
+/// Largest side of snapshot previews sent to the frontend, to keep the base64 payload small
+const SNAPSHOT_PREVIEW_MAX_DIMENSION: u32 = 1024;
+
+/// Capture the current output as a `data:image/png;base64,...` URL for inline previews
+///
+/// Sync so the GPU readback runs on the main thread like every other context
+/// access; the base64 encoding happens after the lock is released.
+#[tauri::command]
+fn capture_snapshot_dataurl(state: tauri::State<'_, Arc<AppState>>) -> Result<String, String> {
+    use base64::Engine;
+
+    let png = state.with_vivid(|ctx| {
+        ctx.capture_snapshot_png(Some(SNAPSHOT_PREVIEW_MAX_DIMENSION))
+            .map_err(|e| e.to_string())
+    }).unwrap_or_else(|| Err("Vivid not initialized".into()))?;
+
+    Ok(format!(
+        "data:image/png;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(png)
+    ))
+}
+
+#[tauri::command]
+fn get_referenced_assets(state: tauri::State<'_, Arc<AppState>>) -> Vec<AssetRefInfo> {
+    state.with_vivid(|ctx| {
+        let project_root = ctx.project_path().map(std::path::PathBuf::from);
+        ctx.chain()
+            .map(|chain| {
+                let assets = match &project_root {
+                    Some(root) => chain.referenced_assets_in(root),
+                    None => chain.referenced_assets(),
+                };
+                assets
+                    .into_iter()
+                    .map(|asset| AssetRefInfo {
+                        path: asset.path.to_string_lossy().into_owned(),
+                        resolved: asset.resolved.to_string_lossy().into_owned(),
+                        kind: format!("{:?}", asset.kind),
+                        exists: asset.exists,
+                        referenced_by: asset.referenced_by,
+                        param: asset.param,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }).unwrap_or_default()
+}
+
+#[tauri::command]
+fn get_output_info(state: tauri::State<'_, Arc<AppState>>) -> Option<OutputInfo> {
+    state.with_vivid(|ctx| {
+        let info = ctx.chain()?.output_operator()?.texture_info()?;
+        Some(OutputInfo {
+            width: info.width,
+            height: info.height,
+            format: info.format,
+            format_name: info.format_name().map(String::from),
+            has_alpha: info.has_alpha,
+            color_space: format!("{:?}", ctx.output_color_space()),
+            alpha_mode: format!("{:?}", ctx.output_alpha_mode()),
+        })
+    }).flatten()
+}
+
+#[tauri::command]
+fn get_chain_required_features(state: tauri::State<'_, Arc<AppState>>) -> Vec<String> {
+    state.with_vivid(|ctx| ctx.chain_required_features()).unwrap_or_default()
+}
+
+#[tauri::command]
+fn get_operator_source_location(
+    state: tauri::State<'_, Arc<AppState>>,
+    op_name: String,
+) -> Option<SourceLocationInfo> {
+    state.with_vivid(|ctx| {
+        let op = ctx.chain()?.operator_by_name(&op_name)?;
+        op.source_location().map(|loc| SourceLocationInfo {
+            file: loc.file,
+            line: loc.line,
+            column: loc.column,
+        })
+    }).flatten()
+}
+
+#[tauri::command]
+fn get_operator_at_source_line(
+    state: tauri::State<'_, Arc<AppState>>,
+    file: String,
+    line: u32,
+) -> Option<String> {
+    state.with_vivid(|ctx| {
+        ctx.chain()?.operator_at_source_line(&file, line).map(|op| op.name())
+    }).flatten()
+}
+
+/// Change counters for cheap polling; refetch only when one moves
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ChainVersionInfo {
+    /// Bumped on structural changes (see `vivid-chain-changed`)
+    pub structure: u64,
+    /// Bumped on parameter and bypass changes
+    pub params: u64,
+}
+
+#[tauri::command]
+fn get_chain_version(state: tauri::State<'_, Arc<AppState>>) -> Option<ChainVersionInfo> {
+    state.with_vivid(|ctx| {
+        let chain = ctx.chain()?;
+        Some(ChainVersionInfo {
+            structure: chain.version(),
+            params: chain.param_version(),
+        })
+    }).flatten()
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ParamSearchMatch {
+    pub operator: String,
+    pub param: String,
+}
+
+/// Find operators exposing a parameter whose name contains the query
+#[tauri::command]
+fn search_params(state: tauri::State<'_, Arc<AppState>>, query: String) -> Vec<ParamSearchMatch> {
+    state.with_vivid(|ctx| {
+        ctx.chain()
+            .map(|chain| {
+                chain
+                    .search_params(&query)
+                    .into_iter()
+                    .map(|(operator, param)| ParamSearchMatch { operator, param })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }).unwrap_or_default()
+}
+
 #[tauri::command]
 fn set_param(
     state: tauri::State<'_, Arc<AppState>>,
@@ -444,66 +984,142 @@ fn set_param(
     param_name: String,
     value: [f32; 4],
 ) -> Result<bool, String> {
-    state.with_vivid(|ctx| {
-        if let Some(chain) = ctx.chain() {
-            if let Some(mut op) = chain.operator_by_name(&op_name) {
-                return op.set_param(&param_name, &value);
+    let mut guard = state.lock_vivid();
+    let vivid = guard.as_mut().ok_or_else(|| "Vivid not initialized".to_string())?;
+    Ok(vivid
+        .operator_mut(&op_name)
+        .map(|op| op.set_param(&param_name, &value))
+        .unwrap_or(false))
+}
+
+/// Drive a parameter from an expression, or revert it to a static value (`None`)
+#[tauri::command]
+fn set_param_expression(
+    state: tauri::State<'_, Arc<AppState>>,
+    op_name: String,
+    param_name: String,
+    expression: Option<String>,
+) -> Result<(), String> {
+    let mut guard = state.lock_vivid();
+    let vivid = guard.as_mut().ok_or_else(|| "Vivid not initialized".to_string())?;
+    let op = vivid
+        .operator_mut(&op_name)
+        .ok_or_else(|| format!("Operator not found: {}", op_name))?;
+    match expression.as_deref().map(str::trim).filter(|e| !e.is_empty()) {
+        Some(expr) => op.set_param_expression(&param_name, expr),
+        None => op.clear_param_expression(&param_name),
+    }
+    .map_err(|e| e.to_string())
+}
+
+/// Reset a parameter to the value saved in chain.cpp (`to_saved`) or to the
+/// operator's built-in default
+#[tauri::command]
+fn reset_param(
+    state: tauri::State<'_, Arc<AppState>>,
+    op_name: String,
+    param_name: String,
+    to_saved: bool,
+) -> Result<bool, String> {
+    let mut guard = state.lock_vivid();
+    let vivid = guard.as_mut().ok_or_else(|| "Vivid not initialized".to_string())?;
+    Ok(vivid
+        .operator_mut(&op_name)
+        .map(|op| {
+            if to_saved {
+                op.reset_param_to_saved(&param_name)
+            } else {
+                op.reset_param_to_default(&param_name)
             }
-        }
-        false
-    }).ok_or_else(|| "Vivid not initialized".to_string())
+        })
+        .unwrap_or(false))
+}
+
+/// Drop to a reduced render scale during interaction, 1.0 to restore full quality
+#[tauri::command]
+fn set_preview_scale(state: tauri::State<'_, Arc<AppState>>, scale: f32) {
+    state.with_vivid_mut(|ctx| ctx.set_preview_scale(scale));
+}
+
+#[tauri::command]
+fn set_asset_watch_enabled(state: tauri::State<'_, Arc<AppState>>, enabled: bool) {
+    state.with_vivid_mut(|ctx| ctx.set_asset_watch_enabled(enabled));
 }
 
 #[tauri::command]
 fn reload_project(state: tauri::State<'_, Arc<AppState>>) -> Result<(), String> {
-    state.with_vivid_mut(|ctx| {
+    let result = state.with_vivid_mut(|ctx| {
         ctx.reload().map_err(|e| e.to_string())
-    }).unwrap_or_else(|| Err("Vivid not initialized".into()))?;
+    }).unwrap_or_else(|| Err("Vivid not initialized".into()));
 
-    // Emit compile status after reload
-    let status = state.with_vivid(|ctx| {
-        let s = ctx.compile_status();
-        CompileStatusPayload {
-            success: s.success,
-            message: s.message,
-            error_line: s.error_line,
-            error_column: s.error_column,
-        }
-    });
-    if let Some(status) = status {
-        state.emit("vivid-compile-status", status);
-    }
+    // Cached operator handles point into the old chain, even if the reload failed
+    state.invalidate_operator_cache();
+    result?;
+
+    // A successful reload resumes rendering after a render crash
+    state.render_crashed.store(false, Ordering::SeqCst);
 
+    // Compile status is emitted by the context's compile callback
     Ok(())
 }
 
-// Input event commands - forward from webview to vivid
+/// Write current parameter values into the project's chain.cpp, or into a copy
+/// of the project at `path`
+#[tauri::command]
+fn save_project(state: tauri::State<'_, Arc<AppState>>, path: Option<String>) -> Result<(), String> {
+    state.with_vivid(|ctx| {
+        match path {
+            Some(path) => ctx.save_project_as(path),
+            None => ctx.save_project(),
+        }
+        .map_err(|e| e.to_string())
+    }).unwrap_or_else(|| Err("Vivid not initialized".into()))
+}
+
+// Input event commands - queue input from the webview, flushed to vivid once per frame
 #[tauri::command]
 fn input_mouse_move(state: tauri::State<'_, Arc<AppState>>, x: f32, y: f32) {
-    state.with_vivid_mut(|ctx| {
-        ctx.set_mouse_position(x, y);
-    });
+    if let Ok(mut pending) = state.pending_input.lock() {
+        pending.mouse_move(x, y);
+    }
 }
 
 #[tauri::command]
 fn input_mouse_button(state: tauri::State<'_, Arc<AppState>>, button: u32, pressed: bool) {
-    state.with_vivid_mut(|ctx| {
-        ctx.set_mouse_button(button, pressed);
-    });
+    if let Ok(mut pending) = state.pending_input.lock() {
+        pending.mouse_button(button, pressed);
+    }
 }
 
 #[tauri::command]
 fn input_scroll(state: tauri::State<'_, Arc<AppState>>, dx: f32, dy: f32) {
-    state.with_vivid_mut(|ctx| {
-        ctx.add_scroll(dx, dy);
-    });
+    if let Ok(mut pending) = state.pending_input.lock() {
+        pending.scroll(dx, dy);
+    }
 }
 
 #[tauri::command]
-fn load_project(state: tauri::State<'_, Arc<AppState>>, path: String) -> Result<(), String> {
-    state.with_vivid_mut(|ctx| {
-        ctx.load_project(&path).map_err(|e| e.to_string())
-    }).unwrap_or_else(|| Err("Vivid not initialized".into()))?;
+fn load_project(
+    state: tauri::State<'_, Arc<AppState>>,
+    path: String,
+    safe_mode: Option<bool>,
+) -> Result<(), String> {
+    let result = state.with_vivid_mut(|ctx| {
+        // Safe mode loads every operator bypassed so a project that crashes the
+        // renderer can still be opened for repair
+        ctx.set_safe_mode(safe_mode.unwrap_or(false));
+        ctx.load_project(&path).map_err(|e| e.to_string())?;
+        apply_project_resolution(ctx);
+        Ok(())
+    }).unwrap_or_else(|| Err("Vivid not initialized".into()));
+
+    // Cached operator handles point into the old chain, even if the load failed
+    state.invalidate_operator_cache();
+    result?;
+
+    // A successful load resumes rendering after a render crash
+    state.render_crashed.store(false, Ordering::SeqCst);
+    recent_projects::add_recent_project(&path);
 
     // Emit project loaded event
     let info = state.with_vivid(|ctx| {
@@ -511,6 +1127,8 @@ fn load_project(state: tauri::State<'_, Arc<AppState>>, path: String) -> Result<
             success: true,
             project_loaded: ctx.has_project(),
             project_path: ctx.project_path(),
+            error: None,
+            error_kind: None,
         }
     });
     if let Some(info) = info {
@@ -520,6 +1138,45 @@ fn load_project(state: tauri::State<'_, Arc<AppState>>, path: String) -> Result<
     Ok(())
 }
 
+/// Delete a project's compilation cache so the next load rebuilds from scratch
+///
+/// Returns `false` if there was no build directory to remove.
+#[tauri::command]
+fn clean_project_build(state: tauri::State<'_, Arc<AppState>>, path: String) -> Result<bool, String> {
+    let project_dir = std::fs::canonicalize(&path)
+        .map_err(|e| format!("Invalid project path: {}", e))?;
+
+    // Prefer the build dir reported by vivid when this project is the loaded one
+    let build_dir = state.with_vivid(|ctx| {
+        let loaded = ctx.project_path().and_then(|p| std::fs::canonicalize(p).ok());
+        if loaded.as_deref() == Some(project_dir.as_path()) {
+            ctx.project_build_dir()
+        } else {
+            None
+        }
+    }).flatten().unwrap_or_else(|| project_dir.join("build"));
+
+    if !build_dir.exists() {
+        return Ok(false);
+    }
+
+    // Only ever delete a directory strictly inside the project
+    let build_dir = std::fs::canonicalize(&build_dir)
+        .map_err(|e| format!("Invalid build directory: {}", e))?;
+    if !build_dir.is_dir() || build_dir == project_dir || !build_dir.starts_with(&project_dir) {
+        return Err(format!(
+            "Refusing to delete {:?}: not a build directory inside {:?}",
+            build_dir, project_dir
+        ));
+    }
+
+    log::info!("[Tauri] Cleaning project build directory: {:?}", build_dir);
+    std::fs::remove_dir_all(&build_dir)
+        .map_err(|e| format!("Failed to remove build directory: {}", e))?;
+
+    Ok(true)
+}
+
 #[tauri::command]
 fn toggle_visualizer(state: tauri::State<'_, Arc<AppState>>) {
     log::info!("[Tauri] toggle_visualizer called");
@@ -539,16 +1196,125 @@ fn get_selected_operator(state: tauri::State<'_, Arc<AppState>>) -> Option<Strin
 fn select_operator(state: tauri::State<'_, Arc<AppState>>, name: String) {
     state.with_vivid_mut(|ctx| {
         ctx.select_operator(&name);
+        // Scroll the graph to the node so list selection and camera stay in sync
+        ctx.frame_operator(&name);
     });
     // Emit selection event
     state.emit("vivid-operator-selected", OperatorSelectedPayload { name: Some(name) });
 }
 
+#[tauri::command]
+fn get_selection(state: tauri::State<'_, Arc<AppState>>) -> Vec<String> {
+    state.with_vivid(|ctx| ctx.selection()).unwrap_or_default()
+}
+
+#[tauri::command]
+fn set_selection(state: tauri::State<'_, Arc<AppState>>, names: Vec<String>) {
+    state.with_vivid_mut(|ctx| {
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        ctx.set_selection(&names);
+    });
+    // Emit selection event
+    state.emit("vivid-selection-changed", SelectionChangedPayload { names });
+}
+
 #[tauri::command]
 fn is_vivid_ready(state: tauri::State<'_, Arc<AppState>>) -> bool {
     state.is_initialized()
 }
 
+// =============================================================================
+// Chain editing commands
+// =============================================================================
+
+/// Why a chain edit was rejected, so the UI can show targeted feedback
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChainEditErrorKind {
+    /// Vivid isn't initialized or no project is loaded
+    NoChain,
+    /// No operator has the given name
+    NotFound,
+    /// The new name is empty, invalid, or already used by another operator
+    InvalidName,
+    /// The target index is past the end of the chain
+    OutOfRange,
+    /// vivid-core rejected the edit for another reason
+    Failed,
+}
+
+/// Chain edit failure with the reason it was rejected
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainEditError {
+    pub kind: ChainEditErrorKind,
+    pub message: String,
+}
+
+impl ChainEditError {
+    fn new(kind: ChainEditErrorKind, message: impl Into<String>) -> Self {
+        Self { kind, message: message.into() }
+    }
+
+    fn no_chain() -> Self {
+        Self::new(ChainEditErrorKind::NoChain, "No project loaded")
+    }
+}
+
+impl From<vivid::Error> for ChainEditError {
+    fn from(e: vivid::Error) -> Self {
+        let kind = match e {
+            vivid::Error::NoChain | vivid::Error::NoProject | vivid::Error::NotInitialized => {
+                ChainEditErrorKind::NoChain
+            }
+            vivid::Error::OperatorNotFound(_) => ChainEditErrorKind::NotFound,
+            vivid::Error::InvalidArgument(_) => ChainEditErrorKind::InvalidName,
+            _ => ChainEditErrorKind::Failed,
+        };
+        Self::new(kind, e.to_string())
+    }
+}
+
+#[tauri::command]
+fn rename_operator(
+    state: tauri::State<'_, Arc<AppState>>,
+    old: String,
+    new: String,
+) -> Result<(), ChainEditError> {
+    {
+        let mut guard = state.lock_vivid();
+        let vivid = guard.as_mut().ok_or_else(ChainEditError::no_chain)?;
+        let mut chain = vivid.ctx.chain().ok_or_else(ChainEditError::no_chain)?;
+        chain.rename_operator(&old, &new)?;
+        // The cache is keyed by name
+        vivid.operator_cache.clear();
+    }
+
+    state.emit_chain_changed("rename_operator");
+    Ok(())
+}
+
+#[tauri::command]
+fn move_operator(
+    state: tauri::State<'_, Arc<AppState>>,
+    name: String,
+    new_index: usize,
+) -> Result<(), ChainEditError> {
+    state.with_vivid(|ctx| {
+        let mut chain = ctx.chain().ok_or_else(ChainEditError::no_chain)?;
+        let count = chain.operator_count();
+        if new_index >= count {
+            return Err(ChainEditError::new(
+                ChainEditErrorKind::OutOfRange,
+                format!("Index {} is out of range for a chain of {} operators", new_index, count),
+            ));
+        }
+        chain.move_operator(&name, new_index).map_err(ChainEditError::from)
+    }).unwrap_or_else(|| Err(ChainEditError::no_chain()))?;
+
+    state.emit_chain_changed("move_operator");
+    Ok(())
+}
+
 // =============================================================================
 // Bundle command
 // =============================================================================
@@ -568,8 +1334,82 @@ pub struct BundleResult {
     pub bundle_path: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleTargetInfo {
+    pub id: String,
+    pub display_name: String,
+    pub available: bool,
+    pub unavailable_reason: Option<String>,
+}
+
+#[tauri::command]
+fn get_bundle_targets() -> Vec<BundleTargetInfo> {
+    vivid::bundle_targets()
+        .into_iter()
+        .map(|target| BundleTargetInfo {
+            id: target.id,
+            display_name: target.display_name,
+            available: target.available,
+            unavailable_reason: target.unavailable_reason,
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExampleProjectInfo {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub path: String,
+}
+
+/// List the example projects shipped with vivid, for the welcome screen gallery
+#[tauri::command]
+fn get_example_projects() -> Vec<ExampleProjectInfo> {
+    vivid::example_projects()
+        .into_iter()
+        .map(|example| ExampleProjectInfo {
+            id: example.id,
+            name: example.name,
+            description: example.description,
+            path: example.path.to_string_lossy().into_owned(),
+        })
+        .collect()
+}
+
 #[tauri::command]
 async fn bundle_project(options: BundleOptions) -> Result<BundleResult, String> {
+    // Bundle in process first so we don't depend on the CLI being built
+    let mut export_options = vivid::BundleOptions::new().with_project_path(&options.project_path);
+    if let Some(ref output_dir) = options.output_dir {
+        export_options = export_options.with_output_dir(output_dir);
+    }
+    if let Some(ref app_name) = options.app_name {
+        export_options = export_options.with_app_name(app_name);
+    }
+    if let Some(ref platform) = options.platform {
+        export_options = export_options.with_platform(platform);
+    }
+
+    // The export doesn't need the context, so the preview keeps rendering while
+    // this worker thread builds the bundle
+    match vivid::export_standalone(export_options) {
+        Ok(path) => {
+            let bundle_path = path.to_string_lossy().into_owned();
+            return Ok(BundleResult {
+                success: true,
+                output: format!("Bundle created: {}", bundle_path),
+                bundle_path: Some(bundle_path),
+            });
+        }
+        Err(e) => log::warn!("In-process export failed, falling back to vivid CLI: {}", e),
+    }
+
+    bundle_project_with_cli(options)
+}
+
+/// Bundle by shelling out to the vivid CLI (fallback for in-process export)
+fn bundle_project_with_cli(options: BundleOptions) -> Result<BundleResult, String> {
     use std::process::Command;
 
     // Find the vivid CLI binary
@@ -712,73 +1552,160 @@ fn get_window_handle(_window: &tauri::WebviewWindow) -> Option<*mut std::ffi::c_
 // Vivid initialization
 // =============================================================================
 
-/// Initialize vivid with the given window
-fn initialize_vivid(
-    state: &Arc<AppState>,
-    window: &tauri::WebviewWindow,
-) -> Result<(), String> {
-    // Only attempt initialization once
-    if state.init_attempted.swap(true, Ordering::SeqCst) {
-        return Ok(());
-    }
+/// Check whether the window can host a vivid rendering surface
+///
+/// Requires a native window handle and a visible window with a non-zero size;
+/// creating the surface before then fails (e.g. the Metal layer isn't attached yet).
+fn is_window_ready(window: &tauri::WebviewWindow) -> bool {
+    get_window_handle(window).is_some()
+        && window.is_visible().unwrap_or(false)
+        && window
+            .inner_size()
+            .map(|size| size.width > 0 && size.height > 0)
+            .unwrap_or(false)
+}
 
-    log::info!("Initializing vivid context...");
+/// Get the vivid root directory (the vivid submodule next to src-tauri)
+fn vivid_root_dir() -> Result<std::path::PathBuf, InitError> {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(|p| p.join("vivid"))
+        .ok_or_else(|| InitError::new(InitErrorKind::AssetPaths, "Failed to get parent directory"))
+}
 
+/// Create a vivid context rendering into the given window
+fn create_vivid_context(
+    window: &tauri::WebviewWindow,
+    vivid_root: &std::path::Path,
+) -> Result<VividContext, InitError> {
     let window_handle = get_window_handle(window)
-        .ok_or_else(|| "Failed to get window handle".to_string())?;
+        .ok_or_else(|| InitError::new(InitErrorKind::Window, "Failed to get window handle"))?;
 
     // Configure asset paths BEFORE creating context
-    let vivid_root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
-        .parent()
-        .ok_or_else(|| "Failed to get parent directory".to_string())?
-        .join("vivid");
-
-    if let Err(e) = vivid::configure_asset_paths(&vivid_root) {
-        log::warn!("Failed to configure asset paths: {:?}", e);
-    } else {
-        log::info!("Configured asset paths for: {:?}", vivid_root);
-    }
+    vivid::configure_asset_paths(vivid_root).map_err(|e| {
+        InitError::new(
+            InitErrorKind::AssetPaths,
+            format!("Failed to configure asset paths for {:?}: {}", vivid_root, e),
+        )
+    })?;
+    log::info!("Configured asset paths for: {:?}", vivid_root);
 
     // Get window size
-    let size = window.inner_size().map_err(|e| e.to_string())?;
+    let size = window
+        .inner_size()
+        .map_err(|e| InitError::new(InitErrorKind::Window, e.to_string()))?;
     let config = vivid::ContextConfig::new(
         size.width.max(1),
         size.height.max(1),
     );
 
     // Create vivid context with window
-    let mut ctx = unsafe { vivid::Context::with_window(window_handle, config) }
-        .map_err(|e| format!("Failed to create vivid context: {:?}", e))?;
+    let (mut ctx, diagnostics) =
+        unsafe { vivid::Context::with_window_diagnostics(window_handle, config) }.map_err(|e| {
+            let message = match e {
+                vivid::Error::ContextCreation(diag) => {
+                    format!("Failed to create vivid context at {}", diag)
+                }
+                e => format!("Failed to create vivid context: {}", e),
+            };
+            InitError::new(InitErrorKind::ContextCreation, message)
+        })?;
+    log::info!(
+        "Created vivid context (backend: {}, adapter: {}, driver: {})",
+        diagnostics.backend.as_deref().unwrap_or("unknown"),
+        diagnostics.adapter_name.as_deref().unwrap_or("unknown"),
+        diagnostics.driver.as_deref().unwrap_or("unknown"),
+    );
 
     // Set vivid root for hot-reload
-    if let Err(e) = ctx.set_root_dir(&vivid_root) {
+    if let Err(e) = ctx.set_root_dir(vivid_root) {
         log::warn!("Failed to set vivid root dir: {:?}", e);
     }
 
     // Disable visualizer UI by default (IDE has its own UI)
     ctx.set_visualizer_visible(false);
 
-    // Auto-load a test project for development
-    let test_project = vivid_root.join("projects/getting-started/02-operator-pipeline");
-    let project_loaded = if test_project.exists() {
-        match ctx.load_project(&test_project) {
+    // Show a neutral screen rather than black while no project is loaded
+    ctx.set_placeholder_enabled(true);
+
+    // Re-import textures/videos edited in external tools without a full reload
+    ctx.set_asset_watch_enabled(true);
+
+    // Forward every recompile (including auto-reloads the IDE didn't trigger), and
+    // mark cached operator handles stale since the chain was rebuilt
+    let app_handle = window.app_handle().clone();
+    let operator_cache_stale = Arc::new(AtomicBool::new(false));
+    let cache_stale = operator_cache_stale.clone();
+    ctx.set_compile_callback(move |status| {
+        cache_stale.store(true, Ordering::SeqCst);
+        let _ = app_handle.emit("vivid-compile-status", CompileStatusPayload::from(status));
+    });
+
+    // A failing operator is isolated by the core; report which one so the UI can flag it
+    let app_handle = window.app_handle().clone();
+    ctx.set_operator_error_callback(move |operator, message| {
+        let _ = app_handle.emit(
+            "vivid-operator-error",
+            OperatorErrorPayload {
+                operator: operator.to_string(),
+                message: message.to_string(),
+            },
+        );
+    });
+
+    Ok(VividContext {
+        ctx,
+        operator_cache: HashMap::new(),
+        operator_cache_stale,
+    })
+}
+
+/// Initialize vivid with the given window
+fn initialize_vivid(
+    state: &Arc<AppState>,
+    window: &tauri::WebviewWindow,
+) -> Result<(), InitError> {
+    // Only attempt initialization once
+    if state.init_attempted.swap(true, Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    log::info!("Initializing vivid context...");
+
+    let vivid_root = vivid_root_dir()?;
+    let mut vivid = create_vivid_context(window, &vivid_root)?;
+
+    // Load the most recently opened project, falling back to the getting-started
+    // project when there's no history or the recent project fails to load
+    let getting_started = vivid_root.join("projects/getting-started/02-operator-pipeline");
+    let candidates: Vec<_> = recent_projects::most_recent_project()
+        .into_iter()
+        .chain(Some(getting_started).filter(|p| p.exists()))
+        .collect();
+
+    let mut loaded_project = None;
+    let mut load_error = None;
+    for project in candidates {
+        match vivid.ctx.load_project(&project) {
             Ok(_) => {
-                log::info!("Loaded test project: {:?}", test_project);
-                true
+                log::info!("Loaded startup project: {:?}", project);
+                apply_project_resolution(&mut vivid.ctx);
+                loaded_project = Some(project);
+                load_error = None;
+                break;
             }
             Err(e) => {
-                log::warn!("Failed to load test project: {:?}", e);
-                false
+                log::warn!("Failed to load startup project {:?}: {:?}", project, e);
+                load_error = Some(format!("Failed to load {:?}: {}", project, e));
             }
         }
-    } else {
-        false
-    };
+    }
+    let project_loaded = loaded_project.is_some();
 
     // Store the context
     {
-        let mut guard = state.vivid.lock().map_err(|_| "Mutex poisoned")?;
-        *guard = Some(VividContext { ctx });
+        let mut guard = state.lock_vivid();
+        *guard = Some(vivid);
     }
 
     log::info!("Vivid initialized successfully!");
@@ -787,11 +1714,62 @@ fn initialize_vivid(
     state.emit("vivid-initialized", VividInitializedPayload {
         success: true,
         project_loaded,
-        project_path: if project_loaded {
-            Some(test_project.to_string_lossy().to_string())
-        } else {
-            None
-        },
+        project_path: loaded_project.map(|p| p.to_string_lossy().to_string()),
+        error_kind: load_error.as_ref().map(|_| InitErrorKind::ProjectLoad),
+        error: load_error,
+    });
+
+    Ok(())
+}
+
+/// Tear down and recreate the vivid context, reloading the current project
+///
+/// Used to recover after GPU device loss (driver reset, GPU switch, sleep/wake)
+/// or a render crash, without restarting the app.
+#[tauri::command]
+fn reinitialize_vivid(app: AppHandle, state: tauri::State<'_, Arc<AppState>>) -> Result<(), String> {
+    log::info!("Reinitializing vivid context...");
+
+    let window = app
+        .get_webview_window("main")
+        .ok_or_else(|| "Main window not found".to_string())?;
+    let vivid_root = vivid_root_dir().map_err(|e| e.to_string())?;
+
+    let project_loaded;
+    let project_path;
+    {
+        let mut guard = state.lock_vivid();
+
+        // Destroy the old context before creating a new one on the same window
+        project_path = guard.as_ref().and_then(|v| v.ctx.project_path());
+        *guard = None;
+
+        let mut vivid = create_vivid_context(&window, &vivid_root).map_err(|e| e.to_string())?;
+        project_loaded = match &project_path {
+            Some(path) => match vivid.ctx.load_project(path) {
+                Ok(_) => {
+                    apply_project_resolution(&mut vivid.ctx);
+                    true
+                }
+                Err(e) => {
+                    log::warn!("Failed to reload project after reinitialization: {:?}", e);
+                    false
+                }
+            },
+            None => false,
+        };
+        *guard = Some(vivid);
+    }
+
+    state.render_crashed.store(false, Ordering::SeqCst);
+    log::info!("Vivid reinitialized successfully!");
+
+    state.emit("vivid-initialized", VividInitializedPayload {
+        success: true,
+        project_loaded,
+        project_path: if project_loaded { project_path } else { None },
+        error: None,
+        error_kind: None,
     });
 
     Ok(())
@@ -830,6 +1808,9 @@ fn create_app_menu(app: &tauri::App) -> Result<Menu<tauri::Wry>, Box<dyn std::er
         .item(&MenuItemBuilder::with_id("save", "Save")
             .accelerator("CmdOrCtrl+S")
             .build(app)?)
+        .item(&MenuItemBuilder::with_id("save_params", "Save Parameters")
+            .accelerator("CmdOrCtrl+Alt+S")
+            .build(app)?)
         .separator()
         .item(&MenuItemBuilder::with_id("reload", "Reload Project")
             .accelerator("CmdOrCtrl+R")
@@ -927,9 +1908,6 @@ fn main() {
     // Create PTY manager
     let pty_manager = Arc::new(pty::PtyManager::new());
 
-    // Frame counter for deferred initialization
-    let frame_count = Arc::new(std::sync::atomic::AtomicU64::new(0));
-
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
@@ -1009,6 +1987,11 @@ fn main() {
                             let _ = win.emit("menu-action", "save");
                         }
                     }
+                    "save_params" => {
+                        if let Some(win) = window {
+                            let _ = win.emit("menu-action", "save_params");
+                        }
+                    }
                     "reload" => {
                         if let Some(win) = window {
                             let _ = win.emit("menu-action", "reload");
@@ -1080,65 +2063,86 @@ fn main() {
             file_ops::write_file,
             file_ops::get_file_name,
             file_ops::create_project,
+            file_ops::duplicate_project,
             file_ops::get_home_dir,
             file_ops::get_vivid_executable_path,
+            // Recent projects
+            recent_projects::get_recent_projects,
             // Vivid state queries
             get_project_info,
             get_compile_status,
             get_performance_stats,
+            get_frame_times,
+            set_perf_metrics,
             get_operators,
             get_operator_params,
+            get_output_values,
+            get_output_info,
+            get_referenced_assets,
+            capture_snapshot_dataurl,
+            get_chain_required_features,
+            get_operator_source_location,
+            get_operator_at_source_line,
+            search_params,
+            get_chain_version,
+            rename_operator,
+            move_operator,
             set_param,
+            reset_param,
+            set_asset_watch_enabled,
+            set_preview_scale,
+            set_param_expression,
             reload_project,
+            save_project,
             // Input forwarding
             input_mouse_move,
             input_mouse_button,
             input_scroll,
             load_project,
+            clean_project_build,
             toggle_visualizer,
             get_selected_operator,
             select_operator,
+            get_selection,
+            set_selection,
             is_vivid_ready,
+            reinitialize_vivid,
             bundle_project,
+            get_bundle_targets,
+            get_example_projects,
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
         .run({
             let state = app_state.clone();
-            let frame_counter = frame_count.clone();
             move |app_handle, event| {
                 match event {
                     RunEvent::Ready => {
                         log::info!("RunEvent::Ready");
                     }
                     RunEvent::MainEventsCleared => {
-                        let frame = frame_counter.fetch_add(1, Ordering::SeqCst);
-
-                        // Wait ~30 frames (about 500ms at 60fps) before trying to init vivid
-                        // This ensures the window/Metal layer is ready
-                        if frame == 30 && !state.init_attempted.load(Ordering::SeqCst) {
-                            log::info!("Attempting vivid initialization on frame {}", frame);
-                            if let Some(window) = app_handle.get_webview_window("main") {
+                        // Initialize vivid as soon as the window can host a surface
+                        if !state.init_attempted.load(Ordering::SeqCst) {
+                            if let Some(window) = app_handle
+                                .get_webview_window("main")
+                                .filter(is_window_ready)
+                            {
+                                log::info!("Window ready, attempting vivid initialization");
                                 if let Err(e) = initialize_vivid(&state, &window) {
                                     log::error!("Failed to initialize vivid: {}", e);
                                     state.emit("vivid-initialized", VividInitializedPayload {
                                         success: false,
                                         project_loaded: false,
                                         project_path: None,
+                                        error: Some(e.message),
+                                        error_kind: Some(e.kind),
                                     });
                                 }
                             }
                         }
 
                         // Render frame on main thread
-                        // Use try_lock to avoid blocking during project loading
-                        if let Ok(guard) = state.vivid.try_lock() {
-                            if let Some(ref vivid_ctx) = *guard {
-                                if let Err(e) = vivid_ctx.ctx.render_frame() {
-                                    log::error!("Render error: {:?}", e);
-                                }
-                            }
-                        }
+                        state.render_frame();
 
                         // Update performance stats
                         state.update_performance_stats();
@@ -0,0 +1,60 @@
+use std::fs;
+use std::path::PathBuf;
+
+/// Maximum number of projects kept in the recent list
+const MAX_RECENT_PROJECTS: usize = 10;
+
+/// Path of the recent projects file in the user's config directory
+fn recent_projects_file() -> Option<PathBuf> {
+    dirs::config_dir().map(|d| d.join("com.vivid.ide").join("recent_projects.json"))
+}
+
+/// Load recently opened project paths, most recent first
+pub fn load_recent_projects() -> Vec<String> {
+    recent_projects_file()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Record a project as the most recently opened
+pub fn add_recent_project(project_path: &str) {
+    let Some(file) = recent_projects_file() else {
+        log::warn!("[Recent] Could not determine config directory");
+        return;
+    };
+
+    let mut projects = load_recent_projects();
+    projects.retain(|p| p != project_path);
+    projects.insert(0, project_path.to_string());
+    projects.truncate(MAX_RECENT_PROJECTS);
+
+    if let Some(dir) = file.parent() {
+        if let Err(e) = fs::create_dir_all(dir) {
+            log::warn!("[Recent] Failed to create config directory: {}", e);
+            return;
+        }
+    }
+
+    match serde_json::to_string_pretty(&projects) {
+        Ok(content) => {
+            if let Err(e) = fs::write(&file, content) {
+                log::warn!("[Recent] Failed to save recent projects: {}", e);
+            }
+        }
+        Err(e) => log::warn!("[Recent] Failed to serialize recent projects: {}", e),
+    }
+}
+
+/// Get the most recently opened project that still exists on disk
+pub fn most_recent_project() -> Option<PathBuf> {
+    load_recent_projects()
+        .into_iter()
+        .map(PathBuf::from)
+        .find(|path| path.join("chain.cpp").is_file())
+}
+
+#[tauri::command]
+pub fn get_recent_projects() -> Vec<String> {
+    load_recent_projects()
+}
@@ -8,9 +8,18 @@ import type {
   CompileStatusInfo,
   OperatorInfo,
   ParamInfo,
+  OperatorValue,
+  OutputInfo,
+  AssetRefInfo,
+  ParamSearchMatch,
+  ChainVersionInfo,
+  SourceLocationInfo,
   PerformanceStats,
+  PerfMetricFlags,
   BundleOptions,
   BundleResult,
+  BundleTargetInfo,
+  ExampleProjectInfo,
 } from "../types";
 
 // Re-export types for convenience
@@ -19,9 +28,18 @@ export type {
   CompileStatusInfo,
   OperatorInfo,
   ParamInfo,
+  OperatorValue,
+  OutputInfo,
+  AssetRefInfo,
+  ParamSearchMatch,
+  ChainVersionInfo,
+  SourceLocationInfo,
   PerformanceStats,
+  PerfMetricFlags,
   BundleOptions,
   BundleResult,
+  BundleTargetInfo,
+  ExampleProjectInfo,
 };
 
 // =============================================================================
@@ -35,6 +53,13 @@ export async function isVividReady(): Promise<boolean> {
   return invoke<boolean>("is_vivid_ready");
 }
 
+/**
+ * Tear down and recreate the vivid context (e.g. after GPU device loss)
+ */
+export async function reinitializeVivid(): Promise<void> {
+  return invoke("reinitialize_vivid");
+}
+
 /**
  * Get current project info
  */
@@ -56,6 +81,21 @@ export async function getPerformanceStats(): Promise<PerformanceStats> {
   return invoke<PerformanceStats>("get_performance_stats");
 }
 
+/**
+ * Get vivid-core's recent per-frame render times in ms, oldest first (a longer
+ * window than PerformanceStats.frame_time_history, e.g. for a histogram)
+ */
+export async function getFrameTimes(): Promise<number[]> {
+  return invoke<number[]>("get_frame_times");
+}
+
+/**
+ * Choose which optional performance metrics the backend gathers
+ */
+export async function setPerfMetrics(enabled: PerfMetricFlags): Promise<void> {
+  return invoke("set_perf_metrics", { enabled });
+}
+
 /**
  * Get all operators in the current chain
  */
@@ -70,6 +110,76 @@ export async function getOperatorParams(opName: string): Promise<ParamInfo[]> {
   return invoke<ParamInfo[]>("get_operator_params", { opName });
 }
 
+/**
+ * Get the current output of every value-producing operator in one call
+ */
+export async function getOutputValues(): Promise<OperatorValue[]> {
+  return invoke<OperatorValue[]>("get_output_values");
+}
+
+/**
+ * Capture the current output as a PNG data URL (downscaled for previews)
+ */
+export async function captureSnapshotDataUrl(): Promise<string> {
+  return invoke<string>("capture_snapshot_dataurl");
+}
+
+/**
+ * List files referenced by FilePath params, flagging missing ones
+ */
+export async function getReferencedAssets(): Promise<AssetRefInfo[]> {
+  return invoke<AssetRefInfo[]>("get_referenced_assets");
+}
+
+/**
+ * Get the chain output texture's size and format, or null if there's no output
+ */
+export async function getOutputInfo(): Promise<OutputInfo | null> {
+  return invoke<OutputInfo | null>("get_output_info");
+}
+
+/**
+ * Get the GPU features the loaded chain requires (empty if only baseline features)
+ */
+export async function getChainRequiredFeatures(): Promise<string[]> {
+  return invoke<string[]>("get_chain_required_features");
+}
+
+/**
+ * Get where an operator is declared in the project source (for jump-to-source)
+ */
+export async function getOperatorSourceLocation(
+  opName: string
+): Promise<SourceLocationInfo | null> {
+  return invoke<SourceLocationInfo | null>("get_operator_source_location", { opName });
+}
+
+/**
+ * Find the operator declared at a source line (for editor-to-graph selection)
+ */
+export async function getOperatorAtSourceLine(
+  file: string,
+  line: number
+): Promise<string | null> {
+  return invoke<string | null>("get_operator_at_source_line", { file, line });
+}
+
+/**
+ * Get the chain's change counters (null if no project is loaded); poll this
+ * and refetch operators/params only when a counter changes
+ */
+export async function getChainVersion(): Promise<ChainVersionInfo | null> {
+  return invoke<ChainVersionInfo | null>("get_chain_version");
+}
+
+/**
+ * Find operators exposing a parameter whose name contains the query
+ * (case-insensitive), e.g. "gamma"
+ */
+export async function searchParams(query: string): Promise<ParamSearchMatch[]> {
+  return invoke<ParamSearchMatch[]>("search_params", { query });
+}
+
 /**
  * Get the currently selected operator
  */
@@ -83,9 +193,26 @@ export async function getSelectedOperator(): Promise<string | null> {
 
 /**
  * Load a project from path
+ *
+ * With safeMode, every operator starts bypassed so a project that crashes the
+ * renderer can be opened for repair.
+ */
+export async function loadProject(path: string, safeMode = false): Promise<void> {
+  return invoke("load_project", { path, safeMode });
+}
+
+/**
+ * List the example projects shipped with vivid (name, description, path)
  */
-export async function loadProject(path: string): Promise<void> {
-  return invoke("load_project", { path });
+export async function getExampleProjects(): Promise<ExampleProjectInfo[]> {
+  return invoke<ExampleProjectInfo[]>("get_example_projects");
+}
+
+/**
+ * Get recently opened project paths, most recent first
+ */
+export async function getRecentProjects(): Promise<string[]> {
+  return invoke<string[]>("get_recent_projects");
 }
 
 /**
@@ -95,6 +222,21 @@ export async function reloadProject(): Promise<void> {
   return invoke("reload_project");
 }
 
+/**
+ * Write current parameter values into the project's chain.cpp, or into a copy
+ * of the project at path (save as)
+ */
+export async function saveProject(path?: string): Promise<void> {
+  return invoke("save_project", { path: path ?? null });
+}
+
+/**
+ * Delete a project's build cache; resolves to false if there was nothing to clean
+ */
+export async function cleanProjectBuild(path: string): Promise<boolean> {
+  return invoke<boolean>("clean_project_build", { path });
+}
+
 // =============================================================================
 // Parameter Control
 // =============================================================================
@@ -110,6 +252,46 @@ export async function setParam(
   return invoke<boolean>("set_param", { opName, paramName, value });
 }
 
+/**
+ * Reset a parameter to the value saved in chain.cpp (toSaved) or to the
+ * operator's built-in default
+ */
+export async function resetParam(
+  opName: string,
+  paramName: string,
+  toSaved: boolean
+): Promise<boolean> {
+  return invoke<boolean>("reset_param", { opName, paramName, toSaved });
+}
+
+/**
+ * Drive a parameter from an expression evaluated every frame (e.g.
+ * "brightness * 2"), or pass null to revert to a static value. Rejects with the
+ * parser's message for invalid expressions.
+ */
+export async function setParamExpression(
+  opName: string,
+  paramName: string,
+  expression: string | null
+): Promise<void> {
+  return invoke("set_param_expression", { opName, paramName, expression });
+}
+
+/**
+ * Enable or disable re-importing referenced assets when they change on disk
+ */
+export async function setAssetWatchEnabled(enabled: boolean): Promise<void> {
+  return invoke("set_asset_watch_enabled", { enabled });
+}
+
+/**
+ * Render at a fraction of full resolution (e.g. 0.5 while dragging a slider);
+ * pass 1 to commit to full quality. Output textures are not reallocated.
+ */
+export async function setPreviewScale(scale: number): Promise<void> {
+  return invoke("set_preview_scale", { scale });
+}
+
 /**
  * Set a float parameter
  */
@@ -171,6 +353,40 @@ export async function selectOperator(name: string): Promise<void> {
   return invoke("select_operator", { name });
 }
 
+/**
+ * Get all selected operators (multi-select)
+ */
+export async function getSelection(): Promise<string[]> {
+  return invoke<string[]>("get_selection");
+}
+
+/**
+ * Replace the selection with several operators (multi-select)
+ */
+export async function setSelection(names: string[]): Promise<void> {
+  return invoke("set_selection", { names });
+}
+
+// =============================================================================
+// Chain Editing
+// =============================================================================
+
+/**
+ * Rename an operator; rejects with a ChainEditError (e.g. kind "invalid_name"
+ * when the name is taken)
+ */
+export async function renameOperator(oldName: string, newName: string): Promise<void> {
+  return invoke("rename_operator", { old: oldName, new: newName });
+}
+
+/**
+ * Move an operator to a new index; rejects with a ChainEditError (kind
+ * "out_of_range" past the end of the chain)
+ */
+export async function moveOperator(name: string, newIndex: number): Promise<void> {
+  return invoke("move_operator", { name, newIndex });
+}
+
 // =============================================================================
 // Visualizer Control
 // =============================================================================
@@ -218,6 +434,13 @@ export async function bundleProject(options: BundleOptions): Promise<BundleResul
   return invoke<BundleResult>("bundle_project", { options });
 }
 
+/**
+ * List bundle target platforms, with unavailable ones marked and explained
+ */
+export async function getBundleTargets(): Promise<BundleTargetInfo[]> {
+  return invoke<BundleTargetInfo[]>("get_bundle_targets");
+}
+
 /**
  * Bundle the currently loaded project
  */
@@ -264,3 +487,10 @@ export async function createProject(
 ): Promise<void> {
   return invoke("create_project", { path, name, template });
 }
+
+/**
+ * Duplicate a project directory to a new location (excluding build artifacts)
+ */
+export async function duplicateProject(src: string, dst: string): Promise<void> {
+  return invoke("duplicate_project", { src, dst });
+}
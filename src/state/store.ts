@@ -8,6 +8,9 @@ import type {
   VividInitializedPayload,
   CompileStatusPayload,
   OperatorSelectedPayload,
+  RendererCrashedPayload,
+  OperatorErrorPayload,
+  ChainChangedPayload,
 } from "../types";
 import { listen } from "../api/tauri";
 import * as vivid from "../api/vivid";
@@ -35,6 +38,8 @@ class Store {
   private listeners: Set<StateListener> = new Set();
   private keyListeners: Map<StateKey, Set<StateListener>> = new Map();
   private unlistenFns: UnlistenFn[] = [];
+  // Last chain structure version seen; reset when the chain is rebuilt
+  private chainVersion = 0;
 
   constructor() {
     this.state = this.getInitialState();
@@ -59,6 +64,7 @@ class Store {
         message: null,
         error_line: null,
         error_column: null,
+        diagnostics: [],
       },
 
       // Performance state
@@ -70,6 +76,9 @@ class Store {
         memory_history: [],
         texture_memory_bytes: 0,
         operator_count: 0,
+        session_frames: 0,
+        session_render_seconds: 0,
+        session_uptime_seconds: 0,
       },
 
       // Editor state
@@ -250,6 +259,9 @@ class Store {
       "vivid-initialized",
       (payload) => {
         console.log("[Store] vivid-initialized event:", payload);
+        if (payload.error) {
+          console.error(`[Store] vivid initialization error (${payload.error_kind}):`, payload.error);
+        }
         this.set({
           vividReady: payload.success,
           projectLoaded: payload.project_loaded,
@@ -265,6 +277,7 @@ class Store {
       "vivid-project-loaded",
       (payload) => {
         console.log("[Store] vivid-project-loaded event:", payload);
+        this.chainVersion = 0;
         this.set({
           projectLoaded: payload.project_loaded,
           projectPath: payload.project_path,
@@ -287,16 +300,33 @@ class Store {
             message: payload.message,
             error_line: payload.error_line,
             error_column: payload.error_column,
+            diagnostics: payload.diagnostics,
           },
         });
-        // Refresh operators on recompile
+        // Refresh operators on recompile (the rebuilt chain restarts its version)
         if (payload.success) {
+          this.chainVersion = 0;
           this.refreshOperators();
         }
       }
     );
     this.unlistenFns.push(unlistenCompile);
 
+    // Chain structure changed (rename, reorder, ...)
+    const unlistenChain = await listen<ChainChangedPayload>(
+      "vivid-chain-changed",
+      (payload) => {
+        console.log("[Store] vivid-chain-changed event:", payload);
+        // Events can arrive out of order; only refetch for a newer structure
+        if (payload.version !== 0 && payload.version <= this.chainVersion) {
+          return;
+        }
+        this.chainVersion = payload.version;
+        this.refreshOperators();
+      }
+    );
+    this.unlistenFns.push(unlistenChain);
+
     // Operator selection event
     const unlistenSelection = await listen<OperatorSelectedPayload>(
       "vivid-operator-selected",
@@ -307,6 +337,26 @@ class Store {
     );
     this.unlistenFns.push(unlistenSelection);
 
+    // Render crashed event (render panicked or the context mutex was poisoned)
+    const unlistenCrash = await listen<RendererCrashedPayload>(
+      "vivid-render-crashed",
+      (payload) => {
+        console.error("[Store] vivid-render-crashed event:", payload.message);
+      }
+    );
+    this.unlistenFns.push(unlistenCrash);
+
+    // Operator runtime error event (the failing operator is isolated, output black)
+    const unlistenOpError = await listen<OperatorErrorPayload>(
+      "vivid-operator-error",
+      (payload) => {
+        console.error(`[Store] operator '${payload.operator}' failed:`, payload.message);
+        // Refresh so the operator list picks up runtime_error
+        this.refreshOperators();
+      }
+    );
+    this.unlistenFns.push(unlistenOpError);
+
     console.log("[Store] Event listeners ready");
   }
 
@@ -411,6 +461,11 @@ class Store {
   private perfPollingInterval: number | null = null;
 
   startPerformancePolling(): void {
+    // Only gather memory/GPU/operator metrics while someone is looking at them
+    vivid.setPerfMetrics({ memory: true, gpu: true, operators: true }).catch((e) => {
+      console.error("[Store] Failed to enable perf metrics:", e);
+    });
+
     // Poll performance stats every 500ms for smooth graphs
     this.perfPollingInterval = window.setInterval(async () => {
       if (this.state.vividReady) {
@@ -424,6 +479,9 @@ class Store {
       clearInterval(this.perfPollingInterval);
       this.perfPollingInterval = null;
     }
+    vivid.setPerfMetrics({ memory: false, gpu: false, operators: false }).catch((e) => {
+      console.error("[Store] Failed to disable perf metrics:", e);
+    });
   }
 
   async refreshAll(): Promise<void> {
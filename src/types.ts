@@ -8,6 +8,14 @@ export interface ProjectInfo {
   loaded: boolean;
   project_path: string | null;
   chain_path: string | null;
+  failed_operators: string[];
+  renderer_healthy: boolean;
+}
+
+export interface CompileDiagnostic {
+  message: string;
+  line: number | null;
+  column: number | null;
 }
 
 export interface CompileStatusInfo {
@@ -15,13 +23,20 @@ export interface CompileStatusInfo {
   message: string | null;
   error_line: number | null;
   error_column: number | null;
+  /** Editor markers to show; always empty on success */
+  diagnostics: CompileDiagnostic[];
 }
 
 export interface OperatorInfo {
   name: string;
   type_name: string;
+  category: string;
+  help: string | null;
   output_kind: string;
   bypassed: boolean;
+  bypass_version: number;
+  execution_order: number | null;
+  runtime_error: string | null;
   input_count: number;
   inputs: string[];
 }
@@ -34,6 +49,29 @@ export interface ParamInfo {
   value: [number, number, number, number];
   default_val: [number, number, number, number];
   enum_labels: string[];
+  enum_values: number[];
+  group: string | null;
+  read_only: boolean;
+  help: string | null;
+  /** Display unit (e.g. "Hz", "px"), if any */
+  unit: string | null;
+  /** Expression driving the value each frame, if any */
+  expression: string | null;
+  /** What drives the value; anything but "static" overrides edits */
+  binding: ParamBinding;
+}
+
+export type ParamBinding = "static" | "expression" | "midi" | "automated" | "osc";
+
+export interface OperatorValue {
+  name: string;
+  value: number;
+}
+
+export interface SourceLocationInfo {
+  file: string;
+  line: number;
+  column: number;
 }
 
 export interface PerformanceStats {
@@ -44,14 +82,56 @@ export interface PerformanceStats {
   memory_history: number[];
   texture_memory_bytes: number;
   operator_count: number;
+  session_frames: number;
+  session_render_seconds: number;
+  session_uptime_seconds: number;
+}
+
+export interface ChainVersionInfo {
+  structure: number;
+  params: number;
+}
+
+export interface ParamSearchMatch {
+  operator: string;
+  param: string;
+}
+
+export interface AssetRefInfo {
+  path: string;
+  resolved: string;
+  kind: string;
+  exists: boolean;
+  referenced_by: string;
+  param: string;
+}
+
+export interface OutputInfo {
+  width: number;
+  height: number;
+  format: number;
+  format_name: string | null;
+  has_alpha: boolean;
+  color_space: "Srgb" | "LinearSrgb" | "DisplayP3" | "Unknown";
+  alpha_mode: "Opaque" | "Premultiplied" | "Straight";
+}
+
+export interface PerfMetricFlags {
+  memory: boolean;
+  gpu: boolean;
+  operators: boolean;
 }
 
 // --- Event Payload Types ---
 
+export type InitErrorKind = "window" | "asset_paths" | "context_creation" | "project_load";
+
 export interface VividInitializedPayload {
   success: boolean;
   project_loaded: boolean;
   project_path: string | null;
+  error: string | null;
+  error_kind: InitErrorKind | null;
 }
 
 export interface CompileStatusPayload {
@@ -59,12 +139,44 @@ export interface CompileStatusPayload {
   message: string | null;
   error_line: number | null;
   error_column: number | null;
+  diagnostics: CompileDiagnostic[];
 }
 
 export interface OperatorSelectedPayload {
   name: string | null;
 }
 
+export interface SelectionChangedPayload {
+  names: string[];
+}
+
+export interface RendererCrashedPayload {
+  message: string;
+}
+
+export interface ChainChangedPayload {
+  reason: string;
+  version: number;
+}
+
+export type ChainEditErrorKind =
+  | "no_chain"
+  | "not_found"
+  | "invalid_name"
+  | "out_of_range"
+  | "failed";
+
+/** Rejection returned by chain-editing commands */
+export interface ChainEditError {
+  kind: ChainEditErrorKind;
+  message: string;
+}
+
+export interface OperatorErrorPayload {
+  operator: string;
+  message: string;
+}
+
 // --- Bundle Types ---
 
 export interface BundleOptions {
@@ -80,6 +192,20 @@ export interface BundleResult {
   bundle_path: string | null;
 }
 
+export interface BundleTargetInfo {
+  id: string;
+  display_name: string;
+  available: boolean;
+  unavailable_reason: string | null;
+}
+
+export interface ExampleProjectInfo {
+  id: string;
+  name: string;
+  description: string | null;
+  path: string;
+}
+
 // --- Layout Types ---
 
 export interface LayoutState {
@@ -114,6 +114,9 @@ function createParamControl(opName: string, param: ParamInfo): HTMLElement {
 
   const label = document.createElement("label");
   label.textContent = param.name;
+  if (param.help) {
+    label.title = param.help;
+  }
   wrapper.appendChild(label);
 
   if (isParamFloat(param) || isParamInt(param)) {
@@ -129,6 +132,23 @@ function createParamControl(opName: string, param: ParamInfo): HTMLElement {
     createVecControl(wrapper, opName, param);
   }
 
+  // Driven params are overwritten every frame, so editing them is pointless
+  if (param.binding !== "static") {
+    wrapper.classList.add("driven");
+    label.textContent += ` (${param.binding})`;
+    if (param.expression) {
+      label.title = param.help ? `${param.help}\n= ${param.expression}` : `= ${param.expression}`;
+    }
+  }
+
+  // Computed/display-only and driven params can't be edited
+  if (param.read_only || param.binding !== "static") {
+    wrapper.classList.add("read-only");
+    wrapper.querySelectorAll<HTMLInputElement | HTMLSelectElement>("input, select").forEach((el) => {
+      el.disabled = true;
+    });
+  }
+
   return wrapper;
 }
 
@@ -142,15 +162,17 @@ function createSliderControl(wrapper: HTMLElement, opName: string, param: ParamI
   slider.step = isInt ? "1" : "0.01";
   slider.value = String(param.value[0]);
 
+  const unit = param.unit ? ` ${param.unit}` : "";
+  const format = (value: number) =>
+    (isInt ? String(Math.round(value)) : value.toFixed(2)) + unit;
+
   const valueDisplay = document.createElement("span");
   valueDisplay.className = "param-value";
-  valueDisplay.textContent = isInt
-    ? String(Math.round(param.value[0]))
-    : param.value[0].toFixed(2);
+  valueDisplay.textContent = format(param.value[0]);
 
   slider.addEventListener("input", () => {
     const value = parseFloat(slider.value);
-    valueDisplay.textContent = isInt ? String(Math.round(value)) : value.toFixed(2);
+    valueDisplay.textContent = format(value);
     debouncedSetParam(opName, param.name, value);
   });
 
@@ -195,10 +217,12 @@ function createEnumControl(wrapper: HTMLElement, opName: string, param: ParamInf
   const select = document.createElement("select");
 
   param.enum_labels.forEach((labelText, i) => {
+    // Sparse enums carry explicit values; otherwise value == index
+    const value = param.enum_values[i] ?? i;
     const option = document.createElement("option");
-    option.value = String(i);
+    option.value = String(value);
     option.textContent = labelText;
-    if (Math.round(param.value[0]) === i) {
+    if (Math.round(param.value[0]) === value) {
       option.selected = true;
     }
     select.appendChild(option);
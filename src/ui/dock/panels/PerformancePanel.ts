@@ -56,6 +56,22 @@ export function createPerformancePanel(): HTMLElement {
         <canvas id="perf-memory-graph"></canvas>
       </div>
     </div>
+
+    <div class="perf-section">
+      <div class="perf-section-title">Session</div>
+      <div class="perf-row">
+        <span class="perf-label">Uptime</span>
+        <span id="perf-uptime-value" class="perf-value">--</span>
+      </div>
+      <div class="perf-row">
+        <span class="perf-label">Frames Rendered</span>
+        <span id="perf-session-frames-value" class="perf-value">--</span>
+      </div>
+      <div class="perf-row">
+        <span class="perf-label">Render Time</span>
+        <span id="perf-render-time-value" class="perf-value">--</span>
+      </div>
+    </div>
   `;
 
   // Subscribe to performance stats changes
@@ -129,6 +145,20 @@ function updatePerformanceDisplay(container: HTMLElement, stats: PerformanceStat
     memValue.textContent = latestMem.toFixed(1) + " MB";
   }
 
+  // Update session counters
+  const uptimeValue = container.querySelector("#perf-uptime-value");
+  if (uptimeValue) {
+    uptimeValue.textContent = formatDuration(stats.session_uptime_seconds);
+  }
+  const sessionFramesValue = container.querySelector("#perf-session-frames-value");
+  if (sessionFramesValue) {
+    sessionFramesValue.textContent = stats.session_frames.toLocaleString();
+  }
+  const renderTimeValue = container.querySelector("#perf-render-time-value");
+  if (renderTimeValue) {
+    renderTimeValue.textContent = formatDuration(stats.session_render_seconds);
+  }
+
   // Draw FPS graph
   drawFpsGraph(container, stats.fps_history);
 
@@ -148,6 +178,18 @@ function formatBytes(bytes: number): string {
   return (bytes / (1024 * 1024)).toFixed(1) + " MB";
 }
 
+function formatDuration(seconds: number): string {
+  const total = Math.floor(seconds);
+  const days = Math.floor(total / 86400);
+  const hours = Math.floor((total % 86400) / 3600);
+  const minutes = Math.floor((total % 3600) / 60);
+  const secs = total % 60;
+  if (days > 0) return `${days}d ${hours}h ${minutes}m`;
+  if (hours > 0) return `${hours}h ${minutes}m ${secs}s`;
+  if (minutes > 0) return `${minutes}m ${secs}s`;
+  return `${secs}s`;
+}
+
 // =============================================================================
 // Graph Drawing
 // =============================================================================
@@ -179,10 +179,12 @@ function createEnumControl(wrapper: HTMLElement, opName: string, param: ParamInf
   const select = document.createElement("select");
 
   param.enum_labels.forEach((labelText, i) => {
+    // Sparse enums carry explicit values; otherwise value == index
+    const value = param.enum_values[i] ?? i;
     const option = document.createElement("option");
-    option.value = String(i);
+    option.value = String(value);
     option.textContent = labelText;
-    if (Math.round(param.value[0]) === i) {
+    if (Math.round(param.value[0]) === value) {
       option.selected = true;
     }
     select.appendChild(option);
@@ -41,6 +41,9 @@ async function setupMenuListeners(): Promise<void> {
       case "save":
         await editor.saveFile();
         break;
+      case "save_params":
+        await saveParams();
+        break;
       case "reload":
         await reloadProject();
         break;
@@ -154,6 +157,29 @@ export async function openProject(): Promise<void> {
   }
 }
 
+async function saveParams(): Promise<void> {
+  const state = store.get();
+  if (!state.projectLoaded) return;
+
+  // Don't let the rewritten chain.cpp clobber unsaved edits in the editor
+  const editingChain = state.currentFilePath === state.chainPath;
+  if (editingChain && state.isModified) {
+    alert("Save or discard your changes to chain.cpp before saving parameters.");
+    return;
+  }
+
+  try {
+    await vivid.saveProject();
+    if (editingChain && state.chainPath) {
+      await editor.loadFile(state.chainPath);
+    }
+    console.log("[Menu] Parameters saved");
+  } catch (e) {
+    console.error("[Menu] Failed to save parameters:", e);
+    alert(`Failed to save parameters: ${e}`);
+  }
+}
+
 async function reloadProject(): Promise<void> {
   try {
     await vivid.reloadProject();
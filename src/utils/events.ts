@@ -6,6 +6,7 @@ import { store, subscribeToKey } from "../state/store";
 import * as vivid from "../api/vivid";
 import { jumpToLine, highlightError, clearErrors, dockManager } from "../ui/dock";
 import * as menu from "../ui/menu";
+import type { CompileStatusInfo } from "../types";
 
 // =============================================================================
 // Input Forwarding
@@ -217,7 +218,7 @@ export function setupErrorBanner(): void {
   });
 }
 
-function handleCompileStatus(status: { success: boolean; message: string | null; error_line: number | null; error_column: number | null }): void {
+function handleCompileStatus(status: CompileStatusInfo): void {
   const statusEl = document.getElementById("compile-status");
   const errorBanner = document.getElementById("error-banner");
   const errorMessage = document.getElementById("error-message");
@@ -264,8 +265,11 @@ function handleCompileStatus(status: { success: boolean; message: string | null;
       errorBanner.classList.remove("hidden");
     }
 
-    if (status.error_line) {
-      highlightError(status.error_line, status.error_column || 1, status.message || "");
+    // Replace markers from the diagnostics so none from an earlier failure linger
+    clearErrors();
+    const located = status.diagnostics.find((d) => d.line);
+    if (located?.line) {
+      highlightError(located.line, located.column || 1, located.message);
     }
   }
 }
@@ -282,12 +286,18 @@ export function setupStatusUpdates(): void {
   // Subscribe to vivid ready state
   subscribeToKey("vividReady", (state) => {
     updateVividStatus(state.vividReady ? "connected" : "disconnected");
+    updateOutputInfo();
   });
 
   // Subscribe to project path changes
   subscribeToKey("projectPath", () => {
     menu.updateProjectTitle();
   });
+
+  // Output texture size/format can change whenever the chain recompiles
+  subscribeToKey("compileStatus", () => {
+    updateOutputInfo();
+  });
 }
 
 function updateResolution(): void {
@@ -297,6 +307,23 @@ function updateResolution(): void {
   }
 }
 
+async function updateOutputInfo(): Promise<void> {
+  const outputEl = document.getElementById("output-info");
+  if (!outputEl) return;
+
+  try {
+    const info = await vivid.getOutputInfo();
+    if (info) {
+      const format = info.format_name ?? `format ${info.format}`;
+      outputEl.textContent = `${info.width} \u00d7 ${info.height} ${format}`;
+    } else {
+      outputEl.textContent = "";
+    }
+  } catch {
+    outputEl.textContent = "";
+  }
+}
+
 function updateVividStatus(status: "connected" | "disconnected"): void {
   const statusEl = document.getElementById("status");
   if (!statusEl) return;